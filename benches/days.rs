@@ -0,0 +1,274 @@
+//! Times parsing and each part of every day on the real inputs
+//!
+//! Usage:
+//!   cargo bench --bench days -- [day|all|first..=last] [--baseline <name>] [--save-baseline <name>]
+//!                                [--time <seconds>] [--include-slow]
+//!
+//! Each step is run repeatedly for `--time` seconds (at least once) and the median is reported.
+//! Results are saved to `target/bench/<name>.tsv` (`latest` by default)
+//! and compared with the results saved under the `--baseline` name (`latest` by default),
+//! so running the benchmark on two commits shows the difference between them.
+use advent_of_code_2021::registry::{self, Day, DAYS};
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Upper bound of samples of one step, to keep fast steps from running millions of times
+const MAX_SAMPLES: usize = 100;
+
+struct Options {
+    days: Vec<&'static Day>,
+    baseline: String,
+    save_baseline: String,
+    time: Duration,
+    include_slow: bool,
+}
+
+/// Step of a day which is measured separately
+#[derive(Clone, Copy)]
+enum Step {
+    Parse,
+    Part(u8),
+}
+
+impl Step {
+    fn name(&self) -> String {
+        match self {
+            Step::Parse => "parse".to_string(),
+            Step::Part(part) => format!("part {}", part),
+        }
+    }
+}
+
+/// Timings of one step
+struct Measurement {
+    day: u8,
+    step: String,
+    samples: usize,
+    min: Duration,
+    median: Duration,
+    mean: Duration,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        days: DAYS.iter().collect(),
+        baseline: "latest".to_string(),
+        save_baseline: "latest".to_string(),
+        time: Duration::from_secs(2),
+        include_slow: false,
+    };
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            // passed by `cargo bench`
+            "--bench" => {}
+            "--baseline" => {
+                options.baseline = args.next().ok_or("--baseline requires a name")?.clone();
+            }
+            "--save-baseline" => {
+                options.save_baseline = args
+                    .next()
+                    .ok_or("--save-baseline requires a name")?
+                    .clone();
+            }
+            "--time" => {
+                let seconds = args
+                    .next()
+                    .and_then(|s| s.parse::<f64>().ok())
+                    .filter(|&seconds| seconds >= 0.0)
+                    .ok_or("--time requires a number of seconds")?;
+                options.time = Duration::from_secs_f64(seconds);
+            }
+            "--include-slow" => options.include_slow = true,
+            _ if !arg.starts_with("--") => options.days = registry::parse_days(arg)?,
+            _ => return Err(format!("Unknown argument: {}", arg)),
+        }
+    }
+
+    Ok(options)
+}
+
+/// Runs one step repeatedly, until `time` runs out or there are enough samples
+fn measure(day: &Day, input: &str, step: Step, time: Duration) -> Measurement {
+    let parts = match step {
+        Step::Parse => vec![],
+        Step::Part(part) => vec![part],
+    };
+
+    let start = Instant::now();
+    let mut samples = vec![];
+    while samples.is_empty() || (start.elapsed() < time && samples.len() < MAX_SAMPLES) {
+        let result = day
+            .solver
+            .solve(input, &parts)
+            .unwrap_or_else(|err| panic!("Cannot parse input: {}", err));
+        samples.push(match step {
+            Step::Parse => result.parse_duration,
+            Step::Part(_) => result.parts[0].duration,
+        });
+    }
+
+    samples.sort_unstable();
+    Measurement {
+        day: day.number,
+        step: step.name(),
+        samples: samples.len(),
+        min: samples[0],
+        median: samples[samples.len() / 2],
+        mean: samples.iter().sum::<Duration>() / samples.len() as u32,
+    }
+}
+
+fn results_path(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("bench")
+        .join(format!("{}.tsv", name))
+}
+
+/// Medians of previously saved results, by day and step
+fn load_results(name: &str) -> HashMap<(u8, String), Duration> {
+    let contents = match fs::read_to_string(results_path(name)) {
+        Ok(contents) => contents,
+        Err(_) => return HashMap::new(),
+    };
+
+    contents
+        .lines()
+        .filter(|line| !line.starts_with("# "))
+        .filter_map(|line| {
+            let columns: Vec<&str> = line.split('\t').collect();
+            match columns[..] {
+                [day, step, _samples, _min, median, _mean] => Some((
+                    (day.parse().ok()?, step.to_string()),
+                    Duration::from_nanos(median.parse().ok()?),
+                )),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Saves results, keeping saved results of days and steps which were not measured this time
+fn save_results(name: &str, measurements: &[Measurement]) {
+    let path = results_path(name);
+    let mut lines: Vec<(u8, String, String)> = fs::read_to_string(&path)
+        .unwrap_or_default()
+        .lines()
+        .filter(|line| !line.starts_with("# "))
+        .filter_map(|line| {
+            let (day, rest) = line.split_once('\t')?;
+            let (step, _) = rest.split_once('\t')?;
+            Some((day.parse().ok()?, step.to_string(), line.to_string()))
+        })
+        .filter(|(day, step, _)| {
+            !measurements
+                .iter()
+                .any(|measurement| measurement.day == *day && measurement.step == *step)
+        })
+        .collect();
+    for measurement in measurements {
+        lines.push((
+            measurement.day,
+            measurement.step.clone(),
+            format!(
+                "{}\t{}\t{}\t{}\t{}\t{}",
+                measurement.day,
+                measurement.step,
+                measurement.samples,
+                measurement.min.as_nanos(),
+                measurement.median.as_nanos(),
+                measurement.mean.as_nanos()
+            ),
+        ));
+    }
+    lines.sort();
+
+    let contents = "# day, step, samples, min, median, mean (ns)\n".to_string()
+        + &lines
+            .into_iter()
+            .map(|(_, _, line)| line + "\n")
+            .collect::<String>();
+    if let Err(err) =
+        fs::create_dir_all(path.parent().unwrap()).and_then(|_| fs::write(&path, contents))
+    {
+        eprintln!("Cannot save results to {}: {}", path.display(), err);
+    }
+}
+
+/// Relative change of the median, e.g. "-12.5%"
+fn change(before: Duration, after: Duration) -> String {
+    let percent = (after.as_secs_f64() / before.as_secs_f64() - 1.0) * 100.0;
+    format!("{:+.1}%", percent)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let baseline = load_results(&options.baseline);
+    let mut measurements = vec![];
+    for day in &options.days {
+        let input = match fs::read_to_string(day.input_path()) {
+            Ok(input) => input,
+            Err(err) => {
+                println!(
+                    "day {:2} ({}): cannot read {}: {}",
+                    day.number,
+                    day.title,
+                    day.input_path(),
+                    err
+                );
+                continue;
+            }
+        };
+
+        for step in [Step::Parse, Step::Part(1), Step::Part(2)] {
+            if let Step::Part(part) = step {
+                // marked slow in the registry, too long to be measured in a regular run
+                if !options.include_slow && day.is_slow(part) {
+                    println!(
+                        "day {:2} {:<7} skipped, slow (--include-slow)",
+                        day.number,
+                        step.name()
+                    );
+                    continue;
+                }
+            }
+
+            let measurement = measure(day, &input, step, options.time);
+            let comparison = match baseline.get(&(day.number, measurement.step.clone())) {
+                Some(&before) => format!(
+                    " {} vs {}",
+                    change(before, measurement.median),
+                    options.baseline
+                ),
+                None => String::new(),
+            };
+            println!(
+                "day {:2} {:<7} {:>12?} (min {:?}, mean {:?}, {} samples){}",
+                day.number,
+                measurement.step,
+                measurement.median,
+                measurement.min,
+                measurement.mean,
+                measurement.samples,
+                comparison
+            );
+            measurements.push(measurement);
+        }
+    }
+
+    save_results(&options.save_baseline, &measurements);
+}
@@ -0,0 +1,33 @@
+//! Every parser returns a value or a clean error, without panicking
+#![no_main]
+use advent_of_code_2021::days::*;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    let _ = day_1_sonar_sweep::SonarSweep.parse(input);
+    let _ = day_2_dive::Dive.parse(input);
+    let _ = day_3_binary_diagnostic::BinaryDiagnostic.parse(input);
+    let _ = day_4_giant_squid::GiantSquid.parse(input);
+    let _ = day_5_hydrothermal_venture::HydrothermalVenture.parse(input);
+    let _ = day_6_lanternfish::Lanternfish::PUZZLE.parse(input);
+    let _ = day_7_treachery_of_whales::TreacheryOfWhales.parse(input);
+    let _ = day_8_seven_segment_search::SevenSegmentSearch.parse(input);
+    let _ = day_9_smoke_basin::SmokeBasin.parse(input);
+    let _ = day_10_syntax_scoring::SyntaxScoring.parse(input);
+    let _ = day_11_dumbo_octopus::DumboOctopus::PUZZLE.parse(input);
+    let _ = day_12_passage_pathing::PassagePathing.parse(input);
+    let _ = day_13_transparent_origami::TransparentOrigami.parse(input);
+    let _ = day_14_extended_polymerization::ExtendedPolymerization::PUZZLE.parse(input);
+    let _ = day_15_chiton::Chiton::PUZZLE.parse(input);
+    let _ = day_16_packet_decoder::PacketDecoder.parse(input);
+    let _ = day_17_trick_shot::TrickShot.parse(input);
+    let _ = day_18_snailfish::Snailfish.parse(input);
+    let _ = day_19_beacon_scanner::BeaconScanner::PUZZLE.parse(input);
+    let _ = day_20_trench_map::TrenchMap::PUZZLE.parse(input);
+    let _ = day_21_dirac_dice::DiracDice::PUZZLE.parse(input);
+    let _ = day_22_reactor_reboot::ReactorReboot::PUZZLE.parse(input);
+    let _ = day_23_amphipod::Amphipods.parse(input);
+    let _ = day_24_arithmetic_logic_unit::ArithmeticLogicUnit.parse(input);
+    let _ = day_25_sea_cucumber::SeaCucumbers.parse(input);
+});
@@ -0,0 +1,15 @@
+//! Polymer template and insertion rules: a clean error, or rules written back as the same rules
+#![no_main]
+use advent_of_code_2021::days::day_14_extended_polymerization::ExtendedPolymerization;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(polymerization) = ExtendedPolymerization::PUZZLE.parse(input) {
+        let written = polymerization.to_string();
+        assert_eq!(
+            ExtendedPolymerization::PUZZLE.parse(&written).unwrap(),
+            polymerization
+        );
+    }
+});
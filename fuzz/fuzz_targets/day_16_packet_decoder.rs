@@ -0,0 +1,12 @@
+//! BITS transmission: a clean error, or a packet encoded back as the same packet
+#![no_main]
+use advent_of_code_2021::days::day_16_packet_decoder::PacketDecoder;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(packet) = PacketDecoder.parse(input) {
+        let written = packet.to_string();
+        assert_eq!(PacketDecoder.parse(&written).unwrap(), packet);
+    }
+});
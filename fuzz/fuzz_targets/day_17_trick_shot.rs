@@ -0,0 +1,12 @@
+//! Target area: a clean error, or an area written back as the same area
+#![no_main]
+use advent_of_code_2021::days::day_17_trick_shot::TrickShot;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(target_area) = TrickShot.parse(input) {
+        let written = target_area.to_string();
+        assert_eq!(TrickShot.parse(&written).unwrap(), target_area);
+    }
+});
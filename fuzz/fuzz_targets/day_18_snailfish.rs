@@ -0,0 +1,16 @@
+//! Snailfish numbers: a clean error, or numbers written back as the same numbers, which add up
+#![no_main]
+use advent_of_code_2021::days::day_18_snailfish::Snailfish;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(numbers) = Snailfish.parse(input) {
+        let written: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+        assert_eq!(Snailfish.parse(&written.join("\n")).unwrap(), numbers);
+        // the sum must reduce, a hang shows up as a timeout
+        if !numbers.is_empty() {
+            Snailfish.part_1(&numbers);
+        }
+    }
+});
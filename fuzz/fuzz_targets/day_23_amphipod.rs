@@ -0,0 +1,12 @@
+//! Burrow diagram: a clean error, or a burrow drawn back as the same burrow
+#![no_main]
+use advent_of_code_2021::days::day_23_amphipod::Amphipods;
+use advent_of_code_2021::Solution;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|input: &str| {
+    if let Ok(burrows) = Amphipods.parse(input) {
+        let written = burrows.to_string();
+        assert_eq!(Amphipods.parse(&written).unwrap(), burrows);
+    }
+});
@@ -0,0 +1,80 @@
+//! Known answers from `answers.tsv`, to check solutions against
+use std::fs;
+use std::path::Path;
+
+/// Tab separated: day, part, input file, answer. `\n` stands for a line break in answers.
+pub const ANSWERS_PATH: &str = "answers.tsv";
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct KnownAnswer {
+    pub day: u8,
+    pub part: u8,
+    /// As given in the file, relative to the repository, e.g. `inputs/day_1_sonar_sweep.txt`
+    pub input_path: String,
+    pub answer: String,
+}
+
+/// Parses the contents of `answers.tsv`. Empty lines and lines starting with `# ` are skipped.
+pub fn parse(contents: &str) -> Result<Vec<KnownAnswer>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.is_empty() && !line.starts_with("# "))
+        .map(|(i, line)| {
+            let invalid = || format!("Invalid line {} in answers: {}", i + 1, line);
+            match line.split('\t').collect::<Vec<&str>>()[..] {
+                [day, part, input_path, answer] => Ok(KnownAnswer {
+                    day: day.parse().map_err(|_| invalid())?,
+                    part: part.parse().map_err(|_| invalid())?,
+                    input_path: input_path.to_string(),
+                    answer: answer.replace("\\n", "\n"),
+                }),
+                _ => Err(invalid()),
+            }
+        })
+        .collect()
+}
+
+/// Known answers from a file, usually `ANSWERS_PATH`
+pub fn load<P: AsRef<Path>>(path: P) -> Result<Vec<KnownAnswer>, String> {
+    let path = path.as_ref();
+    let contents = fs::read_to_string(path)
+        .map_err(|err| format!("Cannot read {}: {}", path.display(), err))?;
+    parse(&contents)
+}
+
+/// Known answer of a day's part on the given input file
+pub fn find<'a>(
+    answers: &'a [KnownAnswer],
+    day: u8,
+    part: u8,
+    input_path: &str,
+) -> Option<&'a KnownAnswer> {
+    answers
+        .iter()
+        .find(|known| known.day == day && known.part == part && known.input_path == input_path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_answers() {
+        let answers = parse("# day, part, input, answer\n\n13\t2\tt.txt\t#.\\n.#\n").unwrap();
+        assert_eq!(
+            answers,
+            vec![KnownAnswer {
+                day: 13,
+                part: 2,
+                input_path: "t.txt".to_string(),
+                answer: "#.\n.#".to_string(),
+            }]
+        );
+        assert_eq!(find(&answers, 13, 2, "t.txt"), Some(&answers[0]));
+        assert_eq!(find(&answers, 13, 1, "t.txt"), None);
+
+        assert!(parse("1\t1\tt.txt").is_err());
+        assert!(parse("x\t1\tt.txt\t7").is_err());
+    }
+}
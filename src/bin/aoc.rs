@@ -0,0 +1,592 @@
+/// Runs solutions of any day and part
+///
+/// Usage:
+///   aoc run <day|all|first..=last> [--part <1|2>] [--input <path|-> | --input-text <text> | --test-input]
+///   aoc config [--config <path>] [--set <key>=<value>]...
+///   aoc days [day|all|first..=last]
+///
+/// By default, input of each day is read from `inputs/`, `--test-input` reads it from `test_inputs/`.
+/// `--input -` reads stdin.
+/// Missing inputs are fetched to `inputs/` if `AOC_SESSION` is set, see `input_provider`.
+/// `--format json` prints one JSON record per answer instead of text, other messages go to stderr.
+/// `--parallel` solves the days on `--threads` threads at once (all cores by default) and prints a table
+/// of answers, checked against `answers.tsv` and the example answers of the registry. Days still running after `--timeout` seconds (60) are
+/// given up, parts slower than `--budget` milliseconds (1000) are flagged.
+/// `--config <path>` and `--set <key>=<value>` change puzzle parameters, see `config`.
+/// `aoc config` prints all parameters as a config file.
+/// `aoc days` prints what the registry knows about each day as tab separated values, for scripts.
+///
+/// Examples:
+///   aoc run 15 --part 2 --input inputs/day_15_chiton.txt
+///   aoc run 1..=10
+///   cat inputs/day_1_sonar_sweep.txt | aoc run 1 --input -
+///   aoc run all
+///   aoc run all --parallel --timeout 10
+///   aoc run 6 --set day_6.days_part_2=300
+use advent_of_code_2021::answers;
+use advent_of_code_2021::config::Config;
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::input_provider::{FetchError, InputProvider};
+use advent_of_code_2021::output::{self, Format};
+use advent_of_code_2021::registry::{self, Day, DAYS};
+use advent_of_code_2021::runner::{self, Job, Outcome, Solver};
+use std::env;
+use std::panic;
+use std::path::Path;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "Usage: aoc run <day|all|first..=last> [--part <1|2>] \
+    [--input <path|-> | --input-text <text> | --test-input] [--format <text|json>] \
+    [--parallel [--threads <n>] [--timeout <seconds>] [--budget <ms>]] \
+    [--config <path>] [--set <key>=<value>]...
+       aoc config [--config <path>] [--set <key>=<value>]...
+       aoc days [day|all|first..=last]";
+
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(60);
+const DEFAULT_BUDGET: Duration = Duration::from_millis(1000);
+
+struct Options {
+    days: Vec<&'static Day>,
+    parts: Vec<u8>,
+    input: Option<InputSource>,
+    test_input: bool,
+    format: Format,
+    parallel: Option<Parallel>,
+    config: Config,
+}
+
+/// Settings of `--parallel`
+struct Parallel {
+    threads: usize,
+    timeout: Duration,
+    budget: Duration,
+}
+
+/// Puzzle parameters of the config file, if any, changed by `--set` options
+fn parse_config(path: Option<&String>, sets: &[&String]) -> Result<Config, String> {
+    let mut config = match path {
+        Some(path) => Config::load(path)?,
+        None => Config::default(),
+    };
+    for set in sets {
+        let (key, value) = set.split_once('=').ok_or("--set requires <key>=<value>")?;
+        config.set(key, value)?;
+    }
+    Ok(config)
+}
+
+/// Parses the options of `aoc config`
+fn parse_config_args(args: &[String]) -> Result<Config, String> {
+    let mut args = args.iter();
+    let mut config_path = None;
+    let mut sets = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--config" => config_path = Some(args.next().ok_or("--config requires a path")?),
+            "--set" => sets.push(args.next().ok_or("--set requires <key>=<value>")?),
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    parse_config(config_path, &sets)
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    match args.next().map(String::as_str) {
+        Some("run") => {}
+        _ => return Err(USAGE.to_string()),
+    }
+
+    let days = registry::parse_days(args.next().ok_or(USAGE)?)?;
+    let mut parts = vec![1, 2];
+    let mut input = None;
+    let mut test_input = false;
+    let mut format = Format::Text;
+    let mut parallel = false;
+    let mut threads = None;
+    let mut timeout = None;
+    let mut budget = None;
+    let mut config_path = None;
+    let mut sets = vec![];
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                parts = match args.next().map(String::as_str) {
+                    Some("1") => vec![1],
+                    Some("2") => vec![2],
+                    _ => return Err("--part must be 1 or 2".to_string()),
+                }
+            }
+            "--input" => {
+                input = Some(InputSource::from_path(
+                    args.next().ok_or("--input requires a path")?,
+                ));
+            }
+            "--input-text" => {
+                input = Some(InputSource::Text(
+                    args.next().ok_or("--input-text requires a text")?.clone(),
+                ));
+            }
+            "--test-input" => test_input = true,
+            "--format" => {
+                format = args
+                    .next()
+                    .ok_or("--format requires text or json")?
+                    .parse()?;
+            }
+            "--parallel" => parallel = true,
+            "--threads" => {
+                threads = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => Some(n),
+                    _ => return Err("--threads requires a positive number".to_string()),
+                }
+            }
+            "--timeout" => {
+                timeout = match args.next().map(|seconds| seconds.parse::<f64>()) {
+                    Some(Ok(seconds)) if seconds > 0.0 => Some(Duration::from_secs_f64(seconds)),
+                    _ => return Err("--timeout requires a number of seconds".to_string()),
+                }
+            }
+            "--budget" => {
+                budget = match args.next().map(|ms| ms.parse::<u64>()) {
+                    Some(Ok(ms)) => Some(Duration::from_millis(ms)),
+                    _ => return Err("--budget requires a number of milliseconds".to_string()),
+                }
+            }
+            "--config" => config_path = Some(args.next().ok_or("--config requires a path")?),
+            "--set" => sets.push(args.next().ok_or("--set requires <key>=<value>")?),
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    if !parallel && (threads.is_some() || timeout.is_some() || budget.is_some()) {
+        return Err("--threads, --timeout and --budget require --parallel".to_string());
+    }
+    let parallel = parallel.then(|| Parallel {
+        threads: threads
+            .unwrap_or_else(|| thread::available_parallelism().map_or(1, |threads| threads.get())),
+        timeout: timeout.unwrap_or(DEFAULT_TIMEOUT),
+        budget: budget.unwrap_or(DEFAULT_BUDGET),
+    });
+
+    if input.is_some() && days.len() > 1 {
+        return Err("--input and --input-text can only be used with a single day".to_string());
+    }
+
+    if input.is_some() && test_input {
+        return Err("--input and --input-text cannot be used with --test-input".to_string());
+    }
+
+    Ok(Options {
+        days,
+        parts,
+        input,
+        test_input,
+        format,
+        parallel,
+        config: parse_config(config_path, &sets)?,
+    })
+}
+
+/// Prints anything but answers: to stdout as text, to stderr when stdout is JSON
+fn message(format: Format, text: &str) {
+    match format {
+        Format::Text => println!("{}", text),
+        Format::Json => eprintln!("{}", text),
+    }
+}
+
+/// Runs one day with the given solver and prints its answers. Returns false if the day failed.
+fn run_day(
+    day: &Day,
+    solver: &dyn Solver,
+    parts: &[u8],
+    input: &InputSource,
+    format: Format,
+) -> bool {
+    let contents = match input.read() {
+        Ok(contents) => contents,
+        Err(err) => {
+            message(
+                format,
+                &format!(
+                    "Day {} ({}): cannot read {}: {}",
+                    day.number,
+                    day.title,
+                    input.describe(),
+                    err
+                ),
+            );
+            return false;
+        }
+    };
+
+    match panic::catch_unwind(panic::AssertUnwindSafe(|| solver.solve(&contents, parts))) {
+        Ok(Err(err)) => {
+            message(format, &err.to_string());
+            false
+        }
+        Ok(Ok(result)) => {
+            message(
+                format,
+                &format!(
+                    "Day {} ({}), parsed in {:?}",
+                    day.number, day.title, result.parse_duration
+                ),
+            );
+            for part in result.parts {
+                if format == Format::Json {
+                    println!("{}", output::json_record(day.number, &part));
+                } else if part.answer.contains('\n') {
+                    // multi-line answers (e.g. letters drawn with '#') start on their own line
+                    println!(
+                        "[part {}] ({:?})\n{}",
+                        part.part, part.duration, part.answer
+                    );
+                } else {
+                    println!("[part {}] {} ({:?})", part.part, part.answer, part.duration);
+                }
+            }
+            true
+        }
+        Err(_) => {
+            message(
+                format,
+                &format!("Day {} ({}): failed", day.number, day.title),
+            );
+            false
+        }
+    }
+}
+
+/// How an answer compares to the known one, from `answers.tsv` or the registry
+fn check(known: Option<&str>, answer: &str) -> String {
+    match known {
+        Some(known) if known == answer => "ok".to_string(),
+        Some(known) if known.contains('\n') => "wrong".to_string(),
+        Some(known) => format!("wrong, expected {}", known),
+        None => "unchecked".to_string(),
+    }
+}
+
+/// Solves the days with `runner::run_parallel` and prints a table of the answers, checked against
+/// `answers.tsv` or the registry, followed by a summary. Returns the days which failed, timed out or are wrong.
+fn run_days_parallel(
+    inputs: &[(&'static Day, InputSource)],
+    parts: &[u8],
+    parallel: &Parallel,
+    config: &'static Config,
+    format: Format,
+) -> Vec<u8> {
+    let mut failed_days = vec![];
+    let mut jobs = vec![];
+    let mut days = vec![];
+    for &(day, ref input) in inputs {
+        match input.read() {
+            Ok(contents) => {
+                jobs.push(Job {
+                    solver: config.solver(day.number).unwrap_or(day.solver),
+                    input: contents,
+                    parts: parts.to_vec(),
+                });
+                days.push((day, input));
+            }
+            Err(err) => {
+                message(
+                    format,
+                    &format!(
+                        "Day {} ({}): cannot read {}: {}",
+                        day.number,
+                        day.title,
+                        input.describe(),
+                        err
+                    ),
+                );
+                failed_days.push(day.number);
+            }
+        }
+    }
+    let known_answers = answers::load(answers::ANSWERS_PATH).unwrap_or_else(|err| {
+        message(format, &format!("{}, answers are not checked", err));
+        vec![]
+    });
+
+    let day_count = jobs.len();
+    let start = Instant::now();
+    let outcomes = runner::run_parallel(jobs, parallel.threads, parallel.timeout);
+    let wall_time = start.elapsed();
+
+    // day, part, answer, time, check, budget
+    let mut rows: Vec<[String; 6]> = vec![];
+    let mut counts = [("ok", 0), ("wrong", 0), ("unchecked", 0), ("failed", 0)];
+    let (mut timed_out, mut over_budget) = (0, 0);
+    for ((day, input), outcome) in days.into_iter().zip(outcomes) {
+        let name = format!("{} {}", day.number, day.title);
+        match outcome {
+            Outcome::Solved(result) => {
+                for part in result.parts {
+                    if format == Format::Json {
+                        println!("{}", output::json_record(day.number, &part));
+                    }
+                    let known = match input {
+                        // known answers are for the puzzle's parameters
+                        _ if config.is_changed(day.number) => None,
+                        InputSource::File(path) if *path == day.test_input_path() => {
+                            day.example_answer(part.part)
+                        }
+                        InputSource::File(path) => {
+                            answers::find(&known_answers, day.number, part.part, path)
+                                .map(|known| known.answer.as_str())
+                        }
+                        _ => None,
+                    };
+                    let check = check(known, &part.answer);
+                    let kind = check.split(',').next().unwrap_or_default();
+                    if let Some((_, count)) = counts.iter_mut().find(|(name, _)| *name == kind) {
+                        *count += 1;
+                    }
+                    if kind == "wrong" && !failed_days.contains(&day.number) {
+                        failed_days.push(day.number);
+                    }
+                    let budget = if part.duration > parallel.budget {
+                        over_budget += 1;
+                        "over budget"
+                    } else {
+                        ""
+                    };
+                    let answer = if part.answer.contains('\n') {
+                        format!("<{} lines>", part.answer.lines().count())
+                    } else {
+                        part.answer
+                    };
+                    rows.push([
+                        name.clone(),
+                        part.part.to_string(),
+                        answer,
+                        format!("{:.1?}", part.duration),
+                        check,
+                        budget.to_string(),
+                    ]);
+                }
+            }
+            Outcome::Failed(err) => {
+                counts[3].1 += 1;
+                failed_days.push(day.number);
+                let row = [&name, "-", "", "", &format!("failed: {}", err), ""];
+                rows.push(row.map(str::to_string));
+            }
+            Outcome::TimedOut => {
+                timed_out += 1;
+                failed_days.push(day.number);
+                let timeout = format!("{:?}", parallel.timeout);
+                let row = [&name, "-", "", &timeout, "timed out", ""];
+                rows.push(row.map(str::to_string));
+            }
+        }
+    }
+
+    if format == Format::Text {
+        let mut widths = [0; 6];
+        for row in &rows {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+        for row in &rows {
+            let line: Vec<String> = row
+                .iter()
+                .zip(widths)
+                .map(|(cell, width)| format!("{:width$}", cell, width = width))
+                .collect();
+            println!("{}", line.join("  ").trim_end());
+        }
+    }
+    let counts: Vec<String> = counts
+        .iter()
+        .map(|(name, count)| format!("{} {}", count, name))
+        .collect();
+    message(format, "");
+    message(
+        format,
+        &format!(
+            "{} days in {:?} on {} thread{}: {}, {} timed out, {} over budget",
+            day_count,
+            wall_time,
+            parallel.threads,
+            if parallel.threads == 1 { "" } else { "s" },
+            counts.join(", "),
+            timed_out,
+            over_budget
+        ),
+    );
+    failed_days
+}
+
+/// Input of a day, fetched if it is missing and `AOC_SESSION` is set.
+/// None if the day has no input, an error (already reported) if it cannot be fetched.
+fn day_input(
+    day: &Day,
+    options: &Options,
+    provider: &InputProvider,
+) -> Result<Option<InputSource>, ()> {
+    let input = match &options.input {
+        Some(input) => input.clone(),
+        None if options.test_input => InputSource::File(day.test_input_path()),
+        None => InputSource::File(day.input_path()),
+    };
+    // missing inputs are fetched if AOC_SESSION is set
+    if options.input.is_none() && !options.test_input && !provider.is_cached(day) {
+        match provider.input(day) {
+            Ok(_) => message(
+                options.format,
+                &format!(
+                    "Day {} ({}): fetched input to {}",
+                    day.number,
+                    day.title,
+                    provider.cache_path(day).display()
+                ),
+            ),
+            Err(FetchError::MissingSession) => {}
+            Err(err) => {
+                message(
+                    options.format,
+                    &format!(
+                        "Day {} ({}): cannot fetch input: {}",
+                        day.number, day.title, err
+                    ),
+                );
+                return Err(());
+            }
+        }
+    }
+    // puzzle inputs are personal, some days may have none
+    if let (None, InputSource::File(path)) = (&options.input, &input) {
+        if !Path::new(path).exists() {
+            message(
+                options.format,
+                &format!(
+                    "Day {} ({}): no input at {}, skipped",
+                    day.number, day.title, path
+                ),
+            );
+            return Ok(None);
+        }
+    }
+    Ok(Some(input))
+}
+
+/// Prints the registry entries of the days, one per line
+fn print_days(args: &[String]) -> Result<(), String> {
+    let days = match args {
+        [] => DAYS.iter().collect(),
+        [days] => registry::parse_days(days)?,
+        _ => return Err(USAGE.to_string()),
+    };
+    println!("# day, name, puzzle title, url, input, test input, slow parts");
+    for day in days {
+        let slow_parts: Vec<String> = day.slow_parts.iter().map(u8::to_string).collect();
+        println!(
+            "{}\t{}\t{}\t{}\t{}\t{}\t{}",
+            day.number,
+            day.name(),
+            day.puzzle_title,
+            day.url(),
+            day.input_path(),
+            day.test_input_path(),
+            slow_parts.join(",")
+        );
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if args.first().is_some_and(|command| command == "days") {
+        if let Err(message) = print_days(&args[1..]) {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+        return;
+    }
+    if args.first().is_some_and(|command| command == "config") {
+        match parse_config_args(&args[1..]) {
+            Ok(config) => print!("{}", config),
+            Err(message) => {
+                eprintln!("{}", message);
+                process::exit(2);
+            }
+        }
+        return;
+    }
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let provider = match InputProvider::from_env() {
+        Ok(provider) => provider,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(2);
+        }
+    };
+
+    // solvers of parallel jobs must live as long as their threads, which may outlive main
+    let config: &'static Config = Box::leak(Box::new(options.config));
+    let changes = config.changes();
+    if !changes.is_empty() {
+        message(
+            options.format,
+            &format!("Puzzle parameters: {}", changes.join(", ")),
+        );
+    }
+
+    let start = Instant::now();
+    let mut failed_days = vec![];
+    let mut inputs = vec![];
+    for day in &options.days {
+        match day_input(day, &options, &provider) {
+            Ok(Some(input)) => inputs.push((*day, input)),
+            Ok(None) => {}
+            Err(()) => failed_days.push(day.number),
+        }
+    }
+    match &options.parallel {
+        Some(parallel) => failed_days.extend(run_days_parallel(
+            &inputs,
+            &options.parts,
+            parallel,
+            config,
+            options.format,
+        )),
+        None => {
+            for (day, input) in &inputs {
+                let solver = config.solver(day.number).unwrap_or(day.solver);
+                if !run_day(day, solver, &options.parts, input, options.format) {
+                    failed_days.push(day.number);
+                }
+            }
+        }
+    }
+    let total = start.elapsed();
+
+    // the parallel table ends with its own summary
+    if options.days.len() > 1 && options.parallel.is_none() {
+        message(options.format, "");
+        message(
+            options.format,
+            &format!("{} days in {:?}", options.days.len(), total),
+        );
+    }
+    if !failed_days.is_empty() {
+        failed_days.sort_unstable();
+        failed_days.dedup();
+        message(options.format, &format!("Failed days: {:?}", failed_days));
+        process::exit(1);
+    }
+}
@@ -0,0 +1,7 @@
+/// Solution to an Advent of Code problem, day 24, 2021
+/// https://adventofcode.com/2021/day/24
+use advent_of_code_2021::days::day_24_arithmetic_logic_unit::ArithmeticLogicUnit;
+
+fn main() {
+    advent_of_code_2021::run(ArithmeticLogicUnit);
+}
@@ -0,0 +1,102 @@
+/// Trajectory of the submarine of day 2 as CSV, see `day_2_dive::Submarine`
+///
+/// Usage:
+///   dive [--model <classic|aim>] [<path> | - | --input-text <text>]
+///
+/// Executes the commands with the classic movement model of part 1 or the aim model of part 2
+/// (the default), and prints the state after each command as `step,x,depth,aim` lines,
+/// starting with step 0 at the surface. Commands are read from stdin if no path is given.
+/// If the position no longer fits in 64 bits, the trajectory ends at the step before.
+///
+/// Examples:
+///   dive inputs/day_2_dive.txt > trajectory.csv
+///   dive --model classic --input-text "$(printf 'forward 5\ndown 5')"
+use advent_of_code_2021::days::day_2_dive::{
+    self, Aim, Classic, Dive, Move, MovementModel, Submarine,
+};
+use advent_of_code_2021::input::{self, InputSource};
+use advent_of_code_2021::Solution;
+use std::env;
+use std::io::{self, BufWriter, Write};
+use std::process;
+
+const USAGE: &str = "Usage: dive [--model <classic|aim>] [<path> | - | --input-text <text>]";
+
+enum Model {
+    Classic,
+    Aim,
+}
+
+struct Options {
+    model: Model,
+    source: InputSource,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let (model, rest) = match args {
+        [flag, model, rest @ ..] if flag == "--model" => match model.as_str() {
+            "classic" => (Model::Classic, rest),
+            "aim" => (Model::Aim, rest),
+            _ => return Err("--model requires `classic` or `aim`".to_string()),
+        },
+        [flag] if flag == "--model" => {
+            return Err("--model requires `classic` or `aim`".to_string())
+        }
+        _ => (Model::Aim, args),
+    };
+    let source = input::parse_args(rest).map_err(|_| USAGE.to_string())?;
+    Ok(Options { model, source })
+}
+
+/// Returns the number of commands executed, fewer than given if one overflows
+fn write_trajectory<M: MovementModel>(model: M, commands: &[Move]) -> io::Result<usize> {
+    let mut submarine = Submarine::new(model);
+    let start = submarine.state();
+    let mut steps = 0;
+    let trajectory = submarine.trajectory(commands).inspect(|_| steps += 1);
+    let mut out = BufWriter::new(io::stdout().lock());
+    day_2_dive::write_trajectory(&mut out, start, trajectory)?;
+    out.flush()?;
+    Ok(steps)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let contents = match options.source.read() {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", options.source.describe(), err);
+            process::exit(1);
+        }
+    };
+    let commands = match Dive.parse(&contents) {
+        Ok(commands) => commands,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+    let written = match options.model {
+        Model::Classic => write_trajectory(Classic, &commands),
+        Model::Aim => write_trajectory(Aim, &commands),
+    };
+    match written {
+        Ok(steps) if steps < commands.len() => {
+            eprintln!("Position overflows at step {}", steps + 1);
+            process::exit(1);
+        }
+        Ok(_) => {}
+        Err(err) => {
+            eprintln!("Cannot write to stdout: {}", err);
+            process::exit(1);
+        }
+    }
+}
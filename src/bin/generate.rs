@@ -0,0 +1,121 @@
+/// Generates random puzzle inputs, see `generate`
+///
+/// Usage:
+///   generate <4|12|16|18|19|22> [--size <n>] [--seed <n>]
+///
+/// Prints the input to stdout. The seed defaults to the current time and is printed to stderr,
+/// so that an input can be generated again. For days 16 and 19 the expected answers are
+/// printed to stderr too, as they are known from how the input was built.
+///
+/// Examples:
+///   generate 4 --size 1000 --seed 42
+///   generate 22 --size 5000 | aoc run 22 --input -
+use advent_of_code_2021::generate::{
+    day_12_passage_pathing, day_16_packet_decoder, day_18_snailfish, day_19_beacon_scanner,
+    day_22_reactor_reboot, day_4_giant_squid, Rng,
+};
+use std::env;
+use std::io::{self, Write};
+use std::process;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const USAGE: &str = "Usage: generate <4|12|16|18|19|22> [--size <n>] [--seed <n>]";
+
+struct Options {
+    day: u8,
+    size: Option<usize>,
+    seed: Option<u64>,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let day = match args.next().map(|day| day.parse::<u8>()) {
+        Some(Ok(day)) if [4, 12, 16, 18, 19, 22].contains(&day) => day,
+        Some(_) => return Err(format!("No generator for this day\n{}", USAGE)),
+        None => return Err(USAGE.to_string()),
+    };
+
+    let mut size = None;
+    let mut seed = None;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--size" => {
+                size = Some(
+                    args.next()
+                        .and_then(|size| size.parse().ok())
+                        .ok_or("--size requires a number")?,
+                );
+            }
+            "--seed" => {
+                seed = Some(
+                    args.next()
+                        .and_then(|seed| seed.parse().ok())
+                        .ok_or("--seed requires a number")?,
+                );
+            }
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    Ok(Options { day, size, seed })
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let seed = options.seed.unwrap_or_else(|| {
+        let seed = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|time| time.as_nanos() as u64)
+            .unwrap_or_default();
+        eprintln!("Seed: {}", seed);
+        seed
+    });
+    let mut rng = Rng::new(seed);
+
+    let input = match options.day {
+        4 => {
+            let size = options.size.unwrap_or(day_4_giant_squid::DEFAULT_SIZE);
+            day_4_giant_squid::generate(&mut rng, size)
+        }
+        12 => {
+            let size = options.size.unwrap_or(day_12_passage_pathing::DEFAULT_SIZE);
+            day_12_passage_pathing::generate(&mut rng, size)
+        }
+        16 => {
+            let size = options.size.unwrap_or(day_16_packet_decoder::DEFAULT_SIZE);
+            let transmission = day_16_packet_decoder::generate(&mut rng, size);
+            eprintln!("[part 1] {}", transmission.version_sum);
+            eprintln!("[part 2] {}", transmission.value);
+            transmission.hex + "\n"
+        }
+        18 => {
+            let size = options.size.unwrap_or(day_18_snailfish::DEFAULT_SIZE);
+            day_18_snailfish::generate(&mut rng, size)
+        }
+        19 => {
+            let size = options.size.unwrap_or(day_19_beacon_scanner::DEFAULT_SIZE);
+            let reports = day_19_beacon_scanner::generate(&mut rng, size);
+            eprintln!("[part 1] {}", reports.beacon_count());
+            eprintln!("[part 2] {}", reports.largest_distance());
+            reports.input
+        }
+        22 => {
+            let size = options.size.unwrap_or(day_22_reactor_reboot::DEFAULT_SIZE);
+            day_22_reactor_reboot::generate(&mut rng, size)
+        }
+        _ => unreachable!("Days are checked by parse_args"),
+    };
+
+    if let Err(err) = io::stdout().write_all(input.as_bytes()) {
+        eprintln!("Cannot write input: {}", err);
+        process::exit(1);
+    }
+}
@@ -0,0 +1,134 @@
+/// Measures the memory used by parsing and by each part of the given days, see `profile`
+///
+/// Usage:
+///   profile [day|all|first..=last] [--test-input] [--include-slow]
+///
+/// Input of each day is read from `inputs/`, `--test-input` reads it from `test_inputs/`.
+/// For each step, prints the peak heap usage, the number of allocations and the bytes allocated
+/// in total, and the peak stack usage. Heap usage of a part does not include the parsed input.
+///
+/// Examples:
+///   profile 22
+///   profile all --test-input
+use advent_of_code_2021::profile::{self, Bytes, CountingAllocator, Usage};
+use advent_of_code_2021::registry::{self, Day, DAYS};
+use std::env;
+use std::fs;
+use std::process;
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+const USAGE: &str = "Usage: profile [day|all|first..=last] [--test-input] [--include-slow]";
+
+struct Options {
+    days: Vec<&'static Day>,
+    test_input: bool,
+    include_slow: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        days: DAYS.iter().collect(),
+        test_input: false,
+        include_slow: false,
+    };
+    for arg in args {
+        match arg.as_str() {
+            "--test-input" => options.test_input = true,
+            "--include-slow" => options.include_slow = true,
+            _ if !arg.starts_with("--") => options.days = registry::parse_days(arg)?,
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+fn print_usage(day: u8, step: &str, usage: &Usage) {
+    println!(
+        "{:>3}  {:<7} {:>10} {:>11} {:>10} {:>10}",
+        day,
+        step,
+        Bytes(usage.peak_heap),
+        usage.allocations,
+        Bytes(usage.allocated),
+        Bytes(usage.peak_stack)
+    );
+}
+
+/// Profiles the days and prints their usage, returns false if any day failed
+fn profile_days(options: Options) -> bool {
+    println!("day  step    peak heap allocations  allocated peak stack");
+    let mut success = true;
+    // step with the deepest stack
+    let mut deepest: Option<(u8, String, usize)> = None;
+    for day in options.days {
+        let path = if options.test_input {
+            day.test_input_path()
+        } else {
+            day.input_path()
+        };
+        let input = match fs::read_to_string(&path) {
+            Ok(input) => input,
+            Err(err) => {
+                println!("{:>3}  cannot read {}: {}", day.number, path, err);
+                continue;
+            }
+        };
+
+        let parts: Vec<u8> = [1, 2]
+            .into_iter()
+            .filter(|&part| options.test_input || options.include_slow || !day.is_slow(part))
+            .collect();
+        match day.solver.profile(&input, &parts) {
+            Ok(usage) => {
+                let steps = [("parse".to_string(), usage.parse)].into_iter().chain(
+                    usage
+                        .parts
+                        .iter()
+                        .map(|(part, usage)| (format!("part {}", part), *usage)),
+                );
+                for (step, usage) in steps {
+                    print_usage(day.number, &step, &usage);
+                    if deepest
+                        .as_ref()
+                        .is_none_or(|(_, _, peak_stack)| usage.peak_stack > *peak_stack)
+                    {
+                        deepest = Some((day.number, step, usage.peak_stack));
+                    }
+                }
+                for part in [1, 2].into_iter().filter(|part| !parts.contains(part)) {
+                    println!(
+                        "{:>3}  part {}  skipped, slow (--include-slow)",
+                        day.number, part
+                    );
+                }
+            }
+            Err(err) => {
+                println!("{:>3}  {}", day.number, err);
+                success = false;
+            }
+        }
+    }
+
+    if let Some((day, step, peak_stack)) = deepest {
+        println!();
+        println!("Deepest stack: day {} {}, {}", day, step, Bytes(peak_stack));
+    }
+    success
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    if !profile::thread(move || profile_days(options)) {
+        process::exit(1);
+    }
+}
@@ -0,0 +1,323 @@
+/// Creates the files of a new day and registers it
+///
+/// Usage:
+///   scaffold <day> <title>
+///
+/// Creates the module `src/days/day_<day>_<title>.rs` with a `Solution` stub and tests on
+/// `test_inputs/day_<day>_<title>.txt`, the binary `src/bin/day_<day>_<title>.rs` and an empty test input.
+/// The day is registered in `src/days/mod.rs` and `src/registry.rs`, without example answers,
+/// so the tests on the test input fail until the answers of the example are filled in.
+/// Days that already exist are never overwritten.
+///
+/// Example:
+///   cargo run --bin scaffold -- 26 my_title
+use advent_of_code_2021::registry;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+
+const USAGE: &str = "Usage: scaffold <day> <title>, e.g. scaffold 26 my_title";
+
+/// Day number and title from the command line
+fn parse_args(args: &[String]) -> Result<(u8, String), String> {
+    let (day, title) = match args {
+        [day, title] => (day, title),
+        _ => return Err(USAGE.to_string()),
+    };
+    let day = match day.parse::<u8>() {
+        Ok(day) if day > 0 => day,
+        _ => return Err(format!("Invalid day: {}\n{}", day, USAGE)),
+    };
+    let valid_title = title.starts_with(|c: char| c.is_ascii_lowercase())
+        && title
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !valid_title {
+        return Err(format!(
+            "Invalid title: {} (expected lowercase snake case, e.g. sonar_sweep)",
+            title
+        ));
+    }
+    Ok((day, title.clone()))
+}
+
+/// Words of a title, capitalized
+fn capitalized_words(title: &str) -> Vec<String> {
+    title
+        .split('_')
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            let mut chars = word.chars();
+            let first = chars.next().unwrap().to_ascii_uppercase();
+            first.to_string() + chars.as_str()
+        })
+        .collect()
+}
+
+/// Name of the solution type, e.g. `SonarSweep` for `sonar_sweep`
+fn solution_name(title: &str) -> String {
+    capitalized_words(title).concat()
+}
+
+/// Title of the puzzle page guessed from the title, e.g. `Sonar Sweep` for `sonar_sweep`
+fn puzzle_title(title: &str) -> String {
+    capitalized_words(title).join(" ")
+}
+
+fn module_source(day: u8, title: &str) -> String {
+    let name = solution_name(title);
+    format!(
+        r#"//! Solution to an Advent of Code problem, day {day}, 2021
+//! https://adventofcode.com/2021/day/{day}
+use crate::error::ParseError;
+use crate::Solution;
+
+pub struct {name};
+
+impl Solution for {name} {{
+    const DAY: u8 = {day};
+
+    type Input = Vec<String>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {{
+        Ok(input.lines().map(String::from).collect())
+    }}
+
+    fn part_1(&self, _input: &Self::Input) -> Self::Answer1 {{
+        0
+    }}
+
+    fn part_2(&self, _input: &Self::Input) -> Self::Answer2 {{
+        0
+    }}
+}}
+
+#[cfg(test)]
+mod tests {{
+    use super::*;
+    use std::fs;
+
+    fn test_input() -> <{name} as Solution>::Input {{
+        let input = fs::read_to_string("test_inputs/day_{day}_{title}.txt").unwrap();
+        {name}.parse(&input).unwrap()
+    }}
+
+    #[test]
+    fn part_1_example() {{
+        // fails until replaced with the answer given in the puzzle description
+        assert_eq!({name}.part_1(&test_input()).to_string(), "example answer");
+    }}
+
+    #[test]
+    fn part_2_example() {{
+        // fails until replaced with the answer given in the puzzle description
+        assert_eq!({name}.part_2(&test_input()).to_string(), "example answer");
+    }}
+}}
+"#
+    )
+}
+
+fn binary_source(day: u8, title: &str) -> String {
+    let name = solution_name(title);
+    format!(
+        r#"/// Solution to an Advent of Code problem, day {day}, 2021
+/// https://adventofcode.com/2021/day/{day}
+use advent_of_code_2021::days::day_{day}_{title}::{name};
+
+fn main() {{
+    advent_of_code_2021::run({name});
+}}
+"#
+    )
+}
+
+/// `src/days/mod.rs` with the module added, keeping declarations sorted
+fn register_module(mod_rs: &str, module: &str) -> String {
+    let (header, mut modules): (Vec<&str>, Vec<&str>) = mod_rs
+        .lines()
+        .partition(|line| !line.starts_with("pub mod "));
+    let declaration = format!("pub mod {};", module);
+    modules.push(&declaration);
+    modules.sort_unstable();
+
+    let mut lines = header;
+    lines.extend(modules);
+    lines.join("\n") + "\n"
+}
+
+/// `src/registry.rs` with the day added to `DAYS`, ordered by day number
+fn register_day(registry_rs: &str, day: u8, title: &str) -> Result<String, String> {
+    const ENTRY_START: &str = "    Day {\n        number: ";
+    let not_found = || "Cannot find the list of days in src/registry.rs".to_string();
+
+    let list_start = registry_rs.find("pub const DAYS").ok_or_else(not_found)?;
+    let list_end = list_start
+        + registry_rs[list_start..]
+            .find("\n];")
+            .ok_or_else(not_found)?
+        + 1;
+
+    // before the first entry of a later day, otherwise at the end
+    let mut position = list_end;
+    let mut search_from = list_start;
+    while let Some(offset) = registry_rs[search_from..list_end].find(ENTRY_START) {
+        let entry_start = search_from + offset;
+        let number_start = entry_start + ENTRY_START.len();
+        let number = registry_rs[number_start..]
+            .split(',')
+            .next()
+            .and_then(|number| number.parse::<u8>().ok())
+            .ok_or_else(not_found)?;
+        if number > day {
+            position = entry_start;
+            break;
+        }
+        search_from = number_start;
+    }
+
+    let entry = format!(
+        "    Day {{\n        number: {day},\n        title: \"{title}\",\n        puzzle_title: \"{}\",\n        \
+         solver: &day_{day}_{title}::{},\n        example_answers: None,\n        \
+         slow_parts: &[],\n    }},\n",
+        puzzle_title(title),
+        solution_name(title)
+    );
+    Ok(format!(
+        "{}{}{}",
+        &registry_rs[..position],
+        entry,
+        &registry_rs[position..]
+    ))
+}
+
+fn scaffold(root: &Path, day: u8, title: &str) -> Result<(), String> {
+    let name = format!("day_{}_{}", day, title);
+    let module_path = root.join(format!("src/days/{}.rs", name));
+    let binary_path = root.join(format!("src/bin/{}.rs", name));
+    let test_input_path = root.join(format!("test_inputs/{}.txt", name));
+    let mod_rs_path = root.join("src/days/mod.rs");
+    let registry_path = root.join("src/registry.rs");
+
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("Cannot read {}: {}", path.display(), err))
+    };
+    let write = |path: &Path, contents: &str| {
+        fs::write(path, contents)
+            .map_err(|err| format!("Cannot write {}: {}", path.display(), err))?;
+        println!("Wrote {}", path.display());
+        Ok::<(), String>(())
+    };
+
+    // check everything before writing anything
+    let mod_rs = read(&mod_rs_path)?;
+    if let Some(existing) = registry::find(day) {
+        return Err(format!("Day {} already exists: {}", day, existing.name()));
+    }
+    let day_prefix = format!("pub mod day_{}_", day);
+    if let Some(line) = mod_rs.lines().find(|line| line.starts_with(&day_prefix)) {
+        return Err(format!("Day {} already exists: {}", day, line));
+    }
+    for path in [&module_path, &binary_path] {
+        if path.exists() {
+            return Err(format!("{} already exists", path.display()));
+        }
+    }
+    let registry_rs = register_day(&read(&registry_path)?, day, title)?;
+
+    write(&module_path, &module_source(day, title))?;
+    write(&binary_path, &binary_source(day, title))?;
+    write(&mod_rs_path, &register_module(&mod_rs, &name))?;
+    write(&registry_path, &registry_rs)?;
+    if test_input_path.exists() {
+        println!("Kept {}", test_input_path.display());
+    } else {
+        fs::create_dir_all(root.join("test_inputs"))
+            .map_err(|err| format!("Cannot create test_inputs: {}", err))?;
+        write(&test_input_path, "")?;
+    }
+    println!(
+        "Paste the example into {}, fill in its answers in src/registry.rs and in the tests, \
+         and replace the puzzle title if it differs",
+        test_input_path.display()
+    );
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let (day, title) = match parse_args(&args) {
+        Ok(day_and_title) => day_and_title,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let root = Path::new(env!("CARGO_MANIFEST_DIR"));
+    if let Err(message) = scaffold(root, day, &title) {
+        eprintln!("{}", message);
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn arguments() {
+        assert_eq!(
+            parse_args(&args(&["26", "my_title"])),
+            Ok((26, "my_title".to_string()))
+        );
+        assert!(parse_args(&args(&["26"])).is_err());
+        assert!(parse_args(&args(&["0", "my_title"])).is_err());
+        assert!(parse_args(&args(&["26", "MyTitle"])).is_err());
+        assert!(parse_args(&args(&["26", "my-title"])).is_err());
+        assert!(parse_args(&args(&["26", "_title"])).is_err());
+    }
+
+    #[test]
+    fn names() {
+        assert_eq!(solution_name("sonar_sweep"), "SonarSweep");
+        assert_eq!(solution_name("chiton"), "Chiton");
+        assert_eq!(solution_name("day_2_part"), "Day2Part");
+        assert_eq!(puzzle_title("sonar_sweep"), "Sonar Sweep");
+    }
+
+    #[test]
+    fn module_declarations_stay_sorted() {
+        let mod_rs = "//! Days\npub mod day_10_b;\npub mod day_1_a;\npub mod day_2_c;\n";
+        assert_eq!(
+            register_module(mod_rs, "day_11_d"),
+            "//! Days\npub mod day_10_b;\npub mod day_11_d;\npub mod day_1_a;\npub mod day_2_c;\n"
+        );
+    }
+
+    #[test]
+    fn days_stay_ordered() {
+        let registry_rs = "pub const DAYS: &[Day] = &[\n    Day {\n        number: 1,\n        title: \"a\",\n        solver: &day_1_a::A,\n    },\n    Day {\n        number: 3,\n        title: \"c\",\n        solver: &day_3_c::C,\n    },\n];\n";
+
+        let registered = register_day(registry_rs, 2, "b").unwrap();
+        let positions: Vec<usize> = ["number: 1,", "number: 2,", "number: 3,"]
+            .iter()
+            .map(|number| registered.find(number).unwrap())
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(registered.contains("        solver: &day_2_b::B,\n"));
+        assert!(registered.contains("        example_answers: None,\n"));
+
+        let registered = register_day(registry_rs, 4, "d").unwrap();
+        assert!(registered.ends_with("slow_parts: &[],\n    },\n];\n"));
+
+        assert!(register_day("fn main() {}", 4, "d").is_err());
+    }
+}
@@ -0,0 +1,91 @@
+/// Cleans sonar depths streamed from stdin, see `sonar`
+///
+/// Usage:
+///   sonar [--window <n>] [--threshold <score>]
+///
+/// Reads one depth per line until the end of stdin and prints the cleaned series to stdout,
+/// one depth per line. Blank lines (dropouts), malformed lines and outliers are left out and
+/// reported on stderr as they are read, followed by a report of the anomalies and of the part 1
+/// and part 2 counts with and without outliers. Outliers are depths with a modified z-score
+/// above the threshold (8 by default) in a window of the previous depths (41 by default).
+///
+/// Examples:
+///   sonar < inputs/day_1_sonar_sweep.txt > cleaned.txt
+///   tail -f depths.log | sonar --window 100 --threshold 5
+use advent_of_code_2021::sonar::{Reading, Sonar, DEFAULT_THRESHOLD, DEFAULT_WINDOW};
+use std::env;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+const USAGE: &str = "Usage: sonar [--window <n>] [--threshold <score>]";
+
+struct Options {
+    window: usize,
+    threshold: f64,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        window: DEFAULT_WINDOW,
+        threshold: DEFAULT_THRESHOLD,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => {
+                options.window = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("--window requires a positive number".to_string()),
+                }
+            }
+            "--threshold" => {
+                options.threshold = match args.next().map(|score| score.parse::<f64>()) {
+                    Some(Ok(score)) if score > 0.0 => score,
+                    _ => return Err("--threshold requires a positive number".to_string()),
+                }
+            }
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let mut sonar = Sonar::new(options.window, options.threshold);
+    // stdout is line buffered, each depth is written as soon as its line is read
+    let mut out = io::stdout().lock();
+    for line in io::stdin().lock().lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("Cannot read stdin: {}", err);
+                process::exit(1);
+            }
+        };
+        let written = match sonar.read(&line) {
+            Reading::Depth(depth) => writeln!(out, "{}", depth),
+            Reading::Anomaly(anomaly) => {
+                eprintln!("{}", anomaly);
+                Ok(())
+            }
+        };
+        if let Err(err) = written {
+            eprintln!("Cannot write to stdout: {}", err);
+            process::exit(1);
+        }
+    }
+    if let Err(err) = out.flush() {
+        eprintln!("Cannot write to stdout: {}", err);
+        process::exit(1);
+    }
+    eprint!("{}", sonar.report());
+}
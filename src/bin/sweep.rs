@@ -0,0 +1,81 @@
+/// Sliding window analytics of sonar depths streamed from stdin, see `day_1_sonar_sweep::SweepAnalytics`
+///
+/// Usage:
+///   sweep [--window <n>] [--averages]
+///
+/// Reads one depth per line until the end of stdin, keeping only the current window in memory,
+/// so it works on unbounded logs. Prints increase, decrease and plateau counts of the window
+/// sums (window 3 by default) and the longest increasing run at the end.
+/// `--averages` also prints the moving average of each complete window as soon as it is known.
+///
+/// Examples:
+///   sweep < inputs/day_1_sonar_sweep.txt
+///   tail -f depths.log | sweep --window 10 --averages
+use advent_of_code_2021::days::day_1_sonar_sweep::SweepAnalytics;
+use advent_of_code_2021::error::parse_number;
+use std::env;
+use std::io::{self, BufRead};
+use std::process;
+
+const USAGE: &str = "Usage: sweep [--window <n>] [--averages]";
+
+struct Options {
+    window: usize,
+    averages: bool,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut options = Options {
+        window: 3,
+        averages: false,
+    };
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--window" => {
+                options.window = match args.next().map(|n| n.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => n,
+                    _ => return Err("--window requires a positive number".to_string()),
+                }
+            }
+            "--averages" => options.averages = true,
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+    Ok(options)
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+
+    let mut analytics = SweepAnalytics::new(options.window);
+    for (i, line) in io::stdin().lock().lines().enumerate() {
+        let line = match line {
+            Ok(line) => line,
+            Err(err) => {
+                eprintln!("Cannot read stdin: {}", err);
+                process::exit(1);
+            }
+        };
+        let depth = match parse_number::<i64>(&line) {
+            Ok(depth) => depth,
+            Err(err) => {
+                eprintln!("{}", err.at_line(i + 1));
+                process::exit(1);
+            }
+        };
+        if let Some(average) = analytics.push(depth) {
+            if options.averages {
+                println!("{}", average);
+            }
+        }
+    }
+    print!("{}", analytics.report());
+}
@@ -0,0 +1,236 @@
+/// Animates the simulations of some days in the terminal, or saves them as images
+///
+/// Usage:
+///   visualize <9|11|13|20|23|25> [--part <1|2>] [--input <path|-> | --input-text <text> | --test-input]
+///             [--delay <ms>] [--output <path.png|path.gif> [--scale <n>]]
+///
+/// - day 9: basins growing from the low points, colored by basin
+/// - day 11: octopus energy levels until all of them flash
+/// - day 13: paper after each fold
+/// - day 20: image enhancement, 2 iterations for part 1, 50 for part 2
+/// - day 23: moves of the cheapest solution, the unfolded burrow for part 2
+/// - day 25: sea cucumber herds until they stop
+///
+/// Input of the day is read from `inputs/` by default, `--test-input` reads it from `test_inputs/`.
+/// Frames are drawn `--delay` milliseconds apart (100 by default). When the output is not a terminal,
+/// frames are written one after another instead of replacing each other.
+///
+/// `--output` saves the frames as an animated GIF, or the last frame as a PNG, instead (except for day 23).
+/// Each cell becomes a square of `--scale` pixels (4 by default).
+///
+/// Examples:
+///   visualize 25 --test-input --delay 50
+///   visualize 23 --part 2 > moves.txt
+///   visualize 9 --output basins.png
+use advent_of_code_2021::days::day_11_dumbo_octopus::{self, DumboOctopus};
+use advent_of_code_2021::days::day_13_transparent_origami::{self, TransparentOrigami};
+use advent_of_code_2021::days::day_20_trench_map::{self, TrenchMap};
+use advent_of_code_2021::days::day_23_amphipod::{self, Amphipods};
+use advent_of_code_2021::days::day_25_sea_cucumber::{self, SeaCucumbers};
+use advent_of_code_2021::days::day_9_smoke_basin::{self, SmokeBasin};
+use advent_of_code_2021::image::{self, Image, ToImage};
+use advent_of_code_2021::input::InputSource;
+use advent_of_code_2021::registry;
+use advent_of_code_2021::visualize::{Frame, Player, DEFAULT_DELAY};
+use advent_of_code_2021::Solution;
+use std::env;
+use std::io::{self, IsTerminal};
+use std::process;
+use std::time::Duration;
+
+const USAGE: &str = "Usage: visualize <9|11|13|20|23|25> [--part <1|2>] \
+    [--input <path|-> | --input-text <text> | --test-input] [--delay <ms>] \
+    [--output <path.png|path.gif> [--scale <n>]]";
+
+const DEFAULT_SCALE: usize = 4;
+
+struct Options {
+    day: u8,
+    part: u8,
+    input: InputSource,
+    delay: Duration,
+    /// Image file to write instead of animating in the terminal
+    output: Option<String>,
+    scale: usize,
+}
+
+fn parse_args(args: &[String]) -> Result<Options, String> {
+    let mut args = args.iter();
+    let day = match args.next().map(|day| day.parse::<u8>()) {
+        Some(Ok(day @ (9 | 11 | 13 | 20 | 23 | 25))) => day,
+        Some(_) => {
+            return Err(format!(
+                "Only days 9, 11, 13, 20, 23 and 25 can be animated\n{}",
+                USAGE
+            ))
+        }
+        None => return Err(USAGE.to_string()),
+    };
+    let day_info = registry::find(day).ok_or(format!("Day {} is not implemented", day))?;
+
+    let mut part = 1;
+    let mut input = None;
+    let mut test_input = false;
+    let mut delay = DEFAULT_DELAY;
+    let mut output = None;
+    let mut scale = DEFAULT_SCALE;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--part" => {
+                part = match args.next().map(String::as_str) {
+                    Some("1") => 1,
+                    Some("2") => 2,
+                    _ => return Err("--part must be 1 or 2".to_string()),
+                }
+            }
+            "--input" => {
+                input = Some(InputSource::from_path(
+                    args.next().ok_or("--input requires a path")?,
+                ));
+            }
+            "--input-text" => {
+                input = Some(InputSource::Text(
+                    args.next().ok_or("--input-text requires a text")?.clone(),
+                ));
+            }
+            "--test-input" => test_input = true,
+            "--delay" => {
+                let milliseconds = args
+                    .next()
+                    .and_then(|delay| delay.parse::<u64>().ok())
+                    .ok_or("--delay requires a number of milliseconds")?;
+                delay = Duration::from_millis(milliseconds);
+            }
+            "--output" => {
+                let path = args.next().ok_or("--output requires a path")?;
+                if !path.ends_with(".png") && !path.ends_with(".gif") {
+                    return Err(format!("{} must end with .png or .gif", path));
+                }
+                output = Some(path.clone());
+            }
+            "--scale" => {
+                scale = args
+                    .next()
+                    .and_then(|scale| scale.parse::<usize>().ok())
+                    .filter(|&scale| scale > 0)
+                    .ok_or("--scale requires a positive number")?;
+            }
+            _ => return Err(format!("Unknown argument: {}\n{}", arg, USAGE)),
+        }
+    }
+
+    if day == 23 && output.is_some() {
+        return Err("Day 23 cannot be saved as an image".to_string());
+    }
+
+    let input = match (input, test_input) {
+        (Some(_), true) => {
+            return Err("--input and --input-text cannot be used with --test-input".to_string())
+        }
+        (Some(input), false) => input,
+        (None, true) => InputSource::File(day_info.test_input_path()),
+        (None, false) => InputSource::File(day_info.input_path()),
+    };
+
+    Ok(Options {
+        day,
+        part,
+        input,
+        delay,
+        output,
+        scale,
+    })
+}
+
+/// Streams the frames to the terminal
+fn play<F: Frame, I: Iterator<Item = F>>(frames: I, options: &Options) -> io::Result<()> {
+    let stdout = io::stdout();
+    let redraw = stdout.is_terminal();
+    Player::new(stdout.lock(), options.delay, redraw)
+        .play(frames)
+        .map(|_| ())
+}
+
+/// Plays the frames, or writes them to the output file: all of them to a GIF, the last one to a PNG
+fn show<F: Frame + ToImage, I: Iterator<Item = F>>(frames: I, options: &Options) -> io::Result<()> {
+    let Some(path) = &options.output else {
+        return play(frames, options);
+    };
+
+    let images: Vec<Image> = if path.ends_with(".gif") {
+        frames
+            .map(|frame| frame.to_image().scaled(options.scale))
+            .collect()
+    } else {
+        frames
+            .last()
+            .map(|frame| frame.to_image().scaled(options.scale))
+            .into_iter()
+            .collect()
+    };
+    if path.ends_with(".gif") {
+        image::write_gif(path, &images, options.delay)?;
+        eprintln!("Wrote {} ({} frames)", path, images.len());
+    } else if let Some(image) = images.last() {
+        image::write_png(path, image)?;
+        eprintln!("Wrote {}", path);
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let options = match parse_args(&args) {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+    let contents = match options.input.read() {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", options.input.describe(), err);
+            process::exit(1);
+        }
+    };
+
+    let played = match options.day {
+        9 => SmokeBasin
+            .parse(&contents)
+            .map(|map| show(day_9_smoke_basin::frames(&map), &options)),
+        11 => DumboOctopus::PUZZLE
+            .parse(&contents)
+            .map(|map| show(day_11_dumbo_octopus::frames(&map), &options)),
+        13 => TransparentOrigami
+            .parse(&contents)
+            .map(|problem| show(day_13_transparent_origami::frames(&problem), &options)),
+        20 => TrenchMap::PUZZLE.parse(&contents).map(|input| {
+            let iterations = if options.part == 1 { 2 } else { 50 };
+            show(day_20_trench_map::frames(&input, iterations), &options)
+        }),
+        23 => Amphipods.parse(&contents).map(|burrows| {
+            if options.part == 1 {
+                play(day_23_amphipod::folded_frames(&burrows), &options)
+            } else {
+                play(day_23_amphipod::unfolded_frames(&burrows), &options)
+            }
+        }),
+        25 => SeaCucumbers
+            .parse(&contents)
+            .map(|map| show(day_25_sea_cucumber::frames(&map), &options)),
+        _ => unreachable!("checked by parse_args"),
+    };
+
+    match played {
+        Ok(Ok(_)) => {}
+        Ok(Err(err)) => {
+            eprintln!("Cannot write frames: {}", err);
+            process::exit(1);
+        }
+        Err(err) => {
+            eprintln!("{}", err.for_day(options.day));
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,328 @@
+//! Puzzle parameters, e.g. the number of days in day 6, which the puzzles fix but which can be
+//! changed to explore variants. Parameters are set in a file of `key = value` lines, or with
+//! `--set key=value` on the command line of `aoc`, see `Config::set` for the keys.
+use crate::days::day_11_dumbo_octopus::DumboOctopus;
+use crate::days::day_14_extended_polymerization::ExtendedPolymerization;
+use crate::days::day_15_chiton::Chiton;
+use crate::days::day_19_beacon_scanner::BeaconScanner;
+use crate::days::day_20_trench_map::TrenchMap;
+use crate::days::day_21_dirac_dice::DiracDice;
+use crate::days::day_22_reactor_reboot::ReactorReboot;
+use crate::days::day_6_lanternfish::Lanternfish;
+use crate::runner::Solver;
+use std::fmt;
+use std::fs;
+use std::ops::RangeInclusive;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Parameters of the days which have any
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Config {
+    pub day_6: Lanternfish,
+    pub day_11: DumboOctopus,
+    pub day_14: ExtendedPolymerization,
+    pub day_15: Chiton,
+    pub day_19: BeaconScanner,
+    pub day_20: TrenchMap,
+    pub day_21: DiracDice,
+    pub day_22: ReactorReboot,
+}
+
+impl Default for Config {
+    /// Parameters of the puzzles
+    fn default() -> Config {
+        Config {
+            day_6: Lanternfish::PUZZLE,
+            day_11: DumboOctopus::PUZZLE,
+            day_14: ExtendedPolymerization::PUZZLE,
+            day_15: Chiton::PUZZLE,
+            day_19: BeaconScanner::PUZZLE,
+            day_20: TrenchMap::PUZZLE,
+            day_21: DiracDice::PUZZLE,
+            day_22: ReactorReboot::PUZZLE,
+        }
+    }
+}
+
+fn number<T: FromStr>(key: &str, value: &str) -> Result<T, String> {
+    value
+        .parse()
+        .map_err(|_| format!("{} must be a number, found `{}`", key, value))
+}
+
+/// Number in `range`, for parameters which make a solution take too much memory or time
+/// beyond some value
+fn within<T: FromStr + PartialOrd + fmt::Display>(
+    key: &str,
+    value: &str,
+    range: RangeInclusive<T>,
+) -> Result<T, String> {
+    match number(key, value)? {
+        n if range.contains(&n) => Ok(n),
+        _ => Err(format!(
+            "{} must be from {} to {}, found `{}`",
+            key,
+            range.start(),
+            range.end(),
+            value
+        )),
+    }
+}
+
+impl Config {
+    /// Sets a parameter. Keys are the day and the field of its solution, e.g. `day_6.days_part_2`.
+    pub fn set(&mut self, key: &str, value: &str) -> Result<(), String> {
+        match key {
+            "day_6.days_part_1" => {
+                self.day_6.days_part_1 = within(key, value, 0..=Lanternfish::MAX_DAYS)?
+            }
+            "day_6.days_part_2" => {
+                self.day_6.days_part_2 = within(key, value, 0..=Lanternfish::MAX_DAYS)?
+            }
+            "day_11.steps" => self.day_11.steps = within(key, value, 0..=DumboOctopus::MAX_STEPS)?,
+            "day_14.steps_part_1" => {
+                self.day_14.steps_part_1 =
+                    within(key, value, 0..=ExtendedPolymerization::MAX_STEPS)?
+            }
+            "day_14.steps_part_2" => {
+                self.day_14.steps_part_2 =
+                    within(key, value, 0..=ExtendedPolymerization::MAX_STEPS)?
+            }
+            "day_15.tiling" => self.day_15.tiling = within(key, value, 1..=Chiton::MAX_TILING)?,
+            "day_19.overlap" => {
+                self.day_19.overlap = within(key, value, 1..=BeaconScanner::MAX_OVERLAP)?
+            }
+            "day_20.iterations_part_1" => {
+                self.day_20.iterations_part_1 = within(key, value, 1..=TrenchMap::MAX_ITERATIONS)?
+            }
+            "day_20.iterations_part_2" => {
+                self.day_20.iterations_part_2 = within(key, value, 1..=TrenchMap::MAX_ITERATIONS)?
+            }
+            "day_21.board_size" => {
+                self.day_21.board_size = within(key, value, 1..=DiracDice::MAX_BOARD_SIZE)?
+            }
+            "day_21.winning_score_part_1" => {
+                self.day_21.winning_score_part_1 =
+                    within(key, value, 1..=DiracDice::MAX_WINNING_SCORE_PART_1)?
+            }
+            "day_21.winning_score_part_2" => {
+                self.day_21.winning_score_part_2 =
+                    within(key, value, 1..=DiracDice::MAX_WINNING_SCORE_PART_2)?
+            }
+            "day_22.region" => {
+                self.day_22.region = within(key, value, 0..=ReactorReboot::MAX_REGION)?
+            }
+            _ => return Err(format!("Unknown parameter: {}", key)),
+        }
+        Ok(())
+    }
+
+    /// Every parameter with its value, as keys of `set`
+    fn values(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("day_6.days_part_1", self.day_6.days_part_1.to_string()),
+            ("day_6.days_part_2", self.day_6.days_part_2.to_string()),
+            ("day_11.steps", self.day_11.steps.to_string()),
+            ("day_14.steps_part_1", self.day_14.steps_part_1.to_string()),
+            ("day_14.steps_part_2", self.day_14.steps_part_2.to_string()),
+            ("day_15.tiling", self.day_15.tiling.to_string()),
+            ("day_19.overlap", self.day_19.overlap.to_string()),
+            (
+                "day_20.iterations_part_1",
+                self.day_20.iterations_part_1.to_string(),
+            ),
+            (
+                "day_20.iterations_part_2",
+                self.day_20.iterations_part_2.to_string(),
+            ),
+            ("day_21.board_size", self.day_21.board_size.to_string()),
+            (
+                "day_21.winning_score_part_1",
+                self.day_21.winning_score_part_1.to_string(),
+            ),
+            (
+                "day_21.winning_score_part_2",
+                self.day_21.winning_score_part_2.to_string(),
+            ),
+            ("day_22.region", self.day_22.region.to_string()),
+        ]
+    }
+
+    /// Sets parameters from `key = value` lines, `#` starts a comment
+    pub fn apply(&mut self, contents: &str) -> Result<(), String> {
+        for (i, line) in contents.lines().enumerate() {
+            let line = line.split('#').next().unwrap_or_default().trim();
+            if line.is_empty() {
+                continue;
+            }
+            let (key, value) = line
+                .split_once('=')
+                .ok_or_else(|| format!("line {}: expected `key = value`", i + 1))?;
+            self.set(key.trim(), value.trim())
+                .map_err(|err| format!("line {}: {}", i + 1, err))?;
+        }
+        Ok(())
+    }
+
+    /// Puzzle parameters changed by a config file
+    pub fn load<P: AsRef<Path>>(path: P) -> Result<Config, String> {
+        let path = path.as_ref();
+        let contents = fs::read_to_string(path)
+            .map_err(|err| format!("Cannot read {}: {}", path.display(), err))?;
+        let mut config = Config::default();
+        config
+            .apply(&contents)
+            .map_err(|err| format!("{} {}", path.display(), err))?;
+        Ok(config)
+    }
+
+    /// Parameters which differ from the puzzle's, as `key = value`
+    pub fn changes(&self) -> Vec<String> {
+        let puzzle = Config::default().values();
+        self.values()
+            .into_iter()
+            .zip(puzzle)
+            .filter(|((_, value), (_, puzzle_value))| value != puzzle_value)
+            .map(|((key, value), _)| format!("{} = {}", key, value))
+            .collect()
+    }
+
+    /// Whether a day is solved with other parameters than the puzzle's,
+    /// so its answers cannot be checked against known ones
+    pub fn is_changed(&self, day: u8) -> bool {
+        let prefix = format!("day_{}.", day);
+        self.changes()
+            .iter()
+            .any(|change| change.starts_with(&prefix))
+    }
+
+    /// Solver of a day with these parameters, None for days without parameters
+    pub fn solver(&self, day: u8) -> Option<&dyn Solver> {
+        match day {
+            6 => Some(&self.day_6),
+            11 => Some(&self.day_11),
+            14 => Some(&self.day_14),
+            15 => Some(&self.day_15),
+            19 => Some(&self.day_19),
+            20 => Some(&self.day_20),
+            21 => Some(&self.day_21),
+            22 => Some(&self.day_22),
+            _ => None,
+        }
+    }
+}
+
+/// All parameters as a config file
+impl fmt::Display for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (key, value) in self.values() {
+            writeln!(f, "{} = {}", key, value)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::Count;
+    use crate::Solution;
+    use std::fs;
+
+    #[test]
+    fn set_parameters() {
+        let mut config = Config::default();
+        assert!(config.changes().is_empty());
+        config
+            .apply("# what if\nday_6.days_part_2 = 18 # example\n\nday_21.board_size=12\n")
+            .unwrap();
+        assert_eq!(
+            config.changes(),
+            vec!["day_6.days_part_2 = 18", "day_21.board_size = 12"]
+        );
+        assert!(config.is_changed(6));
+        assert!(!config.is_changed(11));
+        assert_eq!(
+            config.day_6.solve("3,4,3,1,2", &[2]).unwrap().parts[0].answer,
+            "26"
+        );
+
+        let mut written = Config::default();
+        written.apply(&config.to_string()).unwrap();
+        assert_eq!(written, config);
+
+        assert!(config.set("day_6.days", "1").is_err());
+        assert!(config.set("day_15.tiling", "0").is_err());
+        assert!(config.set("day_22.region", "-1").is_err());
+        assert_eq!(
+            config.set("day_22.region", "100000"),
+            Err("day_22.region must be from 0 to 200, found `100000`".to_string())
+        );
+        assert!(config.set("day_21.winning_score_part_2", "1000").is_err());
+        assert!(config.set("day_21.board_size", "1000").is_err());
+        assert!(config.set("day_6.days_part_2", "1000000000").is_err());
+        assert!(config.set("day_14.steps_part_2", "1000000000").is_err());
+        assert!(config.set("day_20.iterations_part_1", "0").is_err());
+        assert_eq!(
+            config.set("day_15.tiling", "100000"),
+            Err("day_15.tiling must be from 1 to 10, found `100000`".to_string())
+        );
+        assert!(config.set("day_11.steps", "1000000000").is_err());
+        assert!(config.set("day_19.overlap", "0").is_err());
+        assert!(config.set("day_19.overlap", "100").is_err());
+        assert!(config.apply("day_11.steps 10").is_err());
+    }
+
+    #[test]
+    fn changed_parameters() {
+        let tiled_once = Chiton { tiling: 1 };
+        let map = tiled_once.parse("19\n11\n").unwrap();
+        assert_eq!(tiled_once.part_2(&map), tiled_once.part_1(&map));
+
+        let short_game = DiracDice {
+            winning_score_part_2: 1,
+            ..DiracDice::PUZZLE
+        };
+        let positions = short_game
+            .parse("Player 1 starting position: 4\nPlayer 2 starting position: 8")
+            .unwrap();
+        // player 1 wins on the first throw in every universe
+        assert_eq!(short_game.part_2(&positions), Count(Some(27)));
+        let small_board = DiracDice {
+            board_size: 3,
+            ..DiracDice::PUZZLE
+        };
+        assert!(small_board
+            .parse("Player 1 starting position: 4\nPlayer 2 starting position: 1")
+            .is_err());
+
+        // counts which overflow are reported instead of panicking
+        let mut config = Config::default();
+        config.set("day_6.days_part_2", "1000").unwrap();
+        assert_eq!(
+            config.day_6.solve("3,4,3,1,2", &[2]).unwrap().parts[0].answer,
+            "overflow"
+        );
+        config.set("day_21.winning_score_part_2", "50").unwrap();
+        assert_eq!(
+            config
+                .day_21
+                .solve(
+                    "Player 1 starting position: 4\nPlayer 2 starting position: 8",
+                    &[2]
+                )
+                .unwrap()
+                .parts[0]
+                .answer,
+            "overflow"
+        );
+
+        // scanners which share too few beacons are reported instead of searched for forever
+        let input = fs::read_to_string("test_inputs/day_19_beacon_scanner.txt").unwrap();
+        config.set("day_19.overlap", "50").unwrap();
+        let solved = config.day_19.solve(&input, &[1, 2]).unwrap();
+        assert_eq!(solved.parts[0].answer, "unresolved");
+        assert_eq!(solved.parts[1].answer, "unresolved");
+    }
+}
@@ -0,0 +1,190 @@
+//! Solution to an Advent of Code problem, day 10, 2021
+//! https://adventofcode.com/2021/day/10
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+
+// https://en.wikipedia.org/wiki/Bracket
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[allow(clippy::enum_variant_names)]
+pub enum Bracket {
+    LeftParenthesis,
+    RightParenthesis,
+    LeftChevron,
+    RightChevron,
+    LeftBracket,
+    RightBracket,
+    LeftBrace,
+    RightBrace,
+}
+
+impl TryFrom<char> for Bracket {
+    type Error = ParseError;
+
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '(' => Ok(Bracket::LeftParenthesis),
+            '[' => Ok(Bracket::LeftBracket),
+            '{' => Ok(Bracket::LeftBrace),
+            '<' => Ok(Bracket::LeftChevron),
+            ')' => Ok(Bracket::RightParenthesis),
+            ']' => Ok(Bracket::RightBracket),
+            '}' => Ok(Bracket::RightBrace),
+            '>' => Ok(Bracket::RightChevron),
+            _ => Err(ParseError::new(
+                &c.to_string(),
+                "expected one of `()[]{}<>`",
+            )),
+        }
+    }
+}
+
+impl Bracket {
+    fn is_opening(&self) -> bool {
+        matches!(
+            self,
+            Bracket::LeftParenthesis
+                | Bracket::LeftChevron
+                | Bracket::LeftBracket
+                | Bracket::LeftBrace
+        )
+    }
+
+    fn complement(&self) -> Bracket {
+        match self {
+            Bracket::LeftParenthesis => Bracket::RightParenthesis,
+            Bracket::RightParenthesis => Bracket::LeftParenthesis,
+            Bracket::LeftChevron => Bracket::RightChevron,
+            Bracket::RightChevron => Bracket::LeftChevron,
+            Bracket::LeftBracket => Bracket::RightBracket,
+            Bracket::RightBracket => Bracket::LeftBracket,
+            Bracket::LeftBrace => Bracket::RightBrace,
+            Bracket::RightBrace => Bracket::LeftBrace,
+        }
+    }
+}
+
+fn wrong_closing_score(b: Bracket) -> Option<u64> {
+    match b {
+        Bracket::RightParenthesis => Some(3),
+        Bracket::RightBracket => Some(57),
+        Bracket::RightBrace => Some(1197),
+        Bracket::RightChevron => Some(25137),
+        _ => None,
+    }
+}
+
+fn part_1_syntax_error_score_if_corrupted(line: &[Bracket]) -> Option<u64> {
+    let mut stack: Vec<Bracket> = Vec::with_capacity(line.len());
+    for &b in line {
+        if b.is_opening() {
+            stack.push(b);
+        } else if stack.pop().unwrap().complement() != b {
+            return wrong_closing_score(b);
+        }
+    }
+    None
+}
+
+fn part_2_completion_string_if_incomplete(line: &[Bracket]) -> Option<Vec<Bracket>> {
+    let mut stack: Vec<Bracket> = Vec::with_capacity(line.len());
+    for &b in line {
+        if b.is_opening() {
+            stack.push(b);
+        } else if stack.pop().unwrap().complement() != b {
+            return None;
+        }
+    }
+
+    match stack.len() {
+        0 => None,
+        _ => Some(
+            stack
+                .into_iter()
+                .rev()
+                .map(|b| b.complement())
+                .collect::<Vec<Bracket>>(),
+        ),
+    }
+}
+
+fn completion_string_score(b: Vec<Bracket>) -> u64 {
+    b.into_iter().fold(0, |prev_score, b| {
+        prev_score * 5
+            + match b {
+                Bracket::RightParenthesis => 1,
+                Bracket::RightBracket => 2,
+                Bracket::RightBrace => 3,
+                Bracket::RightChevron => 4,
+                _ => 0,
+            }
+    })
+}
+
+pub struct SyntaxScoring;
+
+impl Solution for SyntaxScoring {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Vec<Bracket>>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let lines = parse_lines(input, |line| {
+            line.chars()
+                .enumerate()
+                .map(|(i, c)| {
+                    Bracket::try_from(c)
+                        .map_err(|err| ParseError::new(line, err.reason).at_column(i + 1))
+                })
+                .collect::<Result<Vec<Bracket>, ParseError>>()
+        })?;
+        // part 2 takes the middle score of the incomplete lines
+        if !lines
+            .iter()
+            .any(|line| part_2_completion_string_if_incomplete(line).is_some())
+        {
+            return Err(ParseError::new(
+                input,
+                "expected at least one incomplete line",
+            ));
+        }
+        Ok(lines)
+    }
+
+    /// Sum of syntax error scores (corrupted lines)
+    fn part_1(&self, lines: &Self::Input) -> Self::Answer1 {
+        lines
+            .iter()
+            .filter_map(|line| part_1_syntax_error_score_if_corrupted(line))
+            .sum::<u64>()
+    }
+
+    /// Middle score of the incomplete strings
+    fn part_2(&self, lines: &Self::Input) -> Self::Answer2 {
+        let mut scores_for_incomplete_strings = lines
+            .iter()
+            .filter_map(|line| part_2_completion_string_if_incomplete(line))
+            .map(completion_string_score)
+            .collect::<Vec<u64>>();
+        scores_for_incomplete_strings.sort_unstable();
+        scores_for_incomplete_strings[scores_for_incomplete_strings.len() / 2]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn incomplete_lines_are_required() {
+        let lines = SyntaxScoring.parse("(]\n[({\n").unwrap();
+        assert_eq!(SyntaxScoring.part_1(&lines), 57);
+        assert_eq!(SyntaxScoring.part_2(&lines), 82);
+        assert!(SyntaxScoring.parse("").is_err());
+        assert_eq!(
+            SyntaxScoring.parse("(]\n<>\n").unwrap_err().to_string(),
+            "expected at least one incomplete line (`(]\n<>\n`)"
+        );
+    }
+}
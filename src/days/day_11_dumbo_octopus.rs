@@ -0,0 +1,176 @@
+//! Solution to an Advent of Code problem, day 11, 2021
+//! https://adventofcode.com/2021/day/11
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::image::{Image, ToImage};
+use crate::visualize::Frame;
+use crate::Solution;
+
+struct OctopusSimulation {
+    octopus_map: Grid<u32>,
+}
+
+impl OctopusSimulation {
+    fn new(octopus_map: Grid<u32>) -> OctopusSimulation {
+        OctopusSimulation { octopus_map }
+    }
+
+    /// Runs one step and returns the number of flashes in it
+    fn step(&mut self) -> u64 {
+        let octopus_map = &mut self.octopus_map;
+        let mut flash_count: u64 = 0;
+
+        for point in octopus_map.points() {
+            octopus_map[point] += 1;
+        }
+
+        let mut more_flashes = true;
+        while more_flashes {
+            more_flashes = false;
+
+            for point in octopus_map.points() {
+                if octopus_map[point] == 10 {
+                    octopus_map[point] = 11;
+                    flash_count += 1;
+                    more_flashes = true;
+
+                    for neighbor in octopus_map.neighbors_8(point).collect::<Vec<_>>() {
+                        if octopus_map[neighbor] < 10 {
+                            octopus_map[neighbor] += 1;
+                        }
+                    }
+                }
+            }
+        }
+
+        for point in octopus_map.points() {
+            if octopus_map[point] > 10 {
+                octopus_map[point] = 0;
+            }
+        }
+
+        flash_count
+    }
+
+    fn all_flashed(&self) -> bool {
+        self.octopus_map.iter().all(|&energy| energy == 0)
+    }
+}
+
+/// Energy levels after a step, octopuses which flashed in it are drawn as `*`
+pub struct OctopusFrame {
+    step: u64,
+    flash_count: u64,
+    octopus_map: Grid<u32>,
+}
+
+impl Frame for OctopusFrame {
+    fn render(&self) -> String {
+        let map = self.octopus_map.render(|&energy| match energy {
+            0 if self.step > 0 => '*',
+            _ => char::from_digit(energy, 10).unwrap_or('?'),
+        });
+        format!("Step {}, {} flashes\n{}", self.step, self.flash_count, map)
+    }
+}
+
+impl ToImage for OctopusFrame {
+    /// Energy levels in shades of gray, flashes in white
+    fn to_image(&self) -> Image {
+        let mut image_map = self.octopus_map.clone();
+        if self.step > 0 {
+            for point in image_map.points() {
+                if image_map[point] == 0 {
+                    image_map[point] = 9;
+                }
+            }
+        }
+        image_map.to_image()
+    }
+}
+
+/// Initial state and each step, up to the first step in which all octopuses flash
+pub fn frames(octopus_map: &Grid<u32>) -> impl Iterator<Item = OctopusFrame> {
+    let mut simulation = OctopusSimulation::new(octopus_map.clone());
+    let mut step = 0;
+    let mut done = false;
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let flash_count = if step > 0 { simulation.step() } else { 0 };
+        done = step > 0 && simulation.all_flashed();
+        let frame = OctopusFrame {
+            step,
+            flash_count,
+            octopus_map: simulation.octopus_map.clone(),
+        };
+        step += 1;
+        Some(frame)
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DumboOctopus {
+    /// Steps simulated in part 1, at most `MAX_STEPS`
+    pub steps: usize,
+}
+
+impl DumboOctopus {
+    /// Most steps which can be set in `config`
+    pub const MAX_STEPS: usize = 1_000_000;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: DumboOctopus = DumboOctopus { steps: 100 };
+}
+
+impl Solution for DumboOctopus {
+    const DAY: u8 = 11;
+
+    type Input = Grid<u32>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    /// Flash count after `steps` steps (100)
+    fn part_1(&self, octopus_map: &Self::Input) -> Self::Answer1 {
+        let mut simulation = OctopusSimulation::new(octopus_map.clone());
+        (0..self.steps).map(|_| simulation.step()).sum()
+    }
+
+    /// First step in which all octopuses flash
+    fn part_2(&self, octopus_map: &Self::Input) -> Self::Answer2 {
+        let mut simulation = OctopusSimulation::new(octopus_map.clone());
+        let mut step = 0;
+        loop {
+            step += 1;
+            simulation.step();
+            if simulation.all_flashed() {
+                return step;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn frames_until_all_flash() {
+        let input = fs::read_to_string("test_inputs/day_11_dumbo_octopus.txt").unwrap();
+        let octopus_map = DumboOctopus::PUZZLE.parse(&input).unwrap();
+        let frames: Vec<OctopusFrame> = frames(&octopus_map).collect();
+
+        // step 195 is the answer to part 2
+        assert_eq!(frames.len(), 196);
+        assert!(frames[0]
+            .render()
+            .starts_with("Step 0, 0 flashes\n5483143223\n"));
+        assert_eq!(frames[195].render().lines().nth(1), Some("**********"));
+    }
+}
@@ -0,0 +1,95 @@
+//! Solution to an Advent of Code problem, day 12, 2021
+//! https://adventofcode.com/2021/day/12
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+use std::collections::HashMap;
+use std::collections::HashSet;
+
+fn is_small_cave(name: &str) -> bool {
+    name.chars().next().unwrap().is_ascii_lowercase()
+}
+
+fn count_paths_from_cave(
+    adjacent_caves: &HashMap<String, HashSet<String>>,
+    cave: &str,
+    visited_small_caves: &HashSet<&str>,
+    allow_visit_to_one_small_cave_again: bool,
+) -> usize {
+    match cave {
+        "end" => 1,
+        _ => {
+            let mut visited_small_caves_updated = visited_small_caves.clone();
+            if is_small_cave(cave) {
+                visited_small_caves_updated.insert(cave);
+            }
+            adjacent_caves
+                .get(cave)
+                .unwrap()
+                .iter()
+                .map(String::as_str)
+                .filter_map(|next| {
+                    if !visited_small_caves_updated.contains(next) {
+                        Some((next, allow_visit_to_one_small_cave_again))
+                    } else if allow_visit_to_one_small_cave_again && next != "start" {
+                        Some((next, false))
+                    } else {
+                        None
+                    }
+                })
+                .map(|(next, allow_visit_to_one_small_cave_again)| {
+                    count_paths_from_cave(
+                        adjacent_caves,
+                        next,
+                        &visited_small_caves_updated,
+                        allow_visit_to_one_small_cave_again,
+                    )
+                })
+                .sum::<usize>()
+        }
+    }
+}
+
+pub struct PassagePathing;
+
+impl Solution for PassagePathing {
+    const DAY: u8 = 12;
+
+    type Input = HashMap<String, HashSet<String>>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let connections = parse_lines(input, |line| match line.split_once('-') {
+            Some((from, to)) if !from.is_empty() && !to.is_empty() => Ok((from, to)),
+            _ => Err(ParseError::new(line, "expected `<cave>-<cave>`")),
+        })?;
+
+        let mut adjacent_caves: HashMap<String, HashSet<String>> = HashMap::new();
+        for (from, to) in connections {
+            adjacent_caves
+                .entry(from.to_string())
+                .or_default()
+                .insert(to.to_string());
+            adjacent_caves
+                .entry(to.to_string())
+                .or_default()
+                .insert(from.to_string());
+        }
+
+        if !adjacent_caves.contains_key("start") {
+            return Err(ParseError::new(input, "expected a connection from `start`"));
+        }
+
+        Ok(adjacent_caves)
+    }
+
+    /// Path count (no repeated visits to small caves)
+    fn part_1(&self, adjacent_caves: &Self::Input) -> Self::Answer1 {
+        count_paths_from_cave(adjacent_caves, "start", &HashSet::<&str>::new(), false)
+    }
+
+    /// Path count (allow 1 repeated visit to 1 small cave)
+    fn part_2(&self, adjacent_caves: &Self::Input) -> Self::Answer2 {
+        count_paths_from_cave(adjacent_caves, "start", &HashSet::<&str>::new(), true)
+    }
+}
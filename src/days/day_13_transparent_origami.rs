@@ -0,0 +1,213 @@
+//! Solution to an Advent of Code problem, day 13, 2021
+//! https://adventofcode.com/2021/day/13
+use crate::error::{parse_number_in, ParseError};
+use crate::grid::Grid;
+use crate::image::{Image, ToImage};
+use crate::visualize::Frame;
+use crate::Solution;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialOrd, PartialEq, Eq, Ord)]
+struct Dot {
+    x: u64,
+    y: u64,
+}
+
+impl FromStr for Dot {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (str_x, str_y) = s
+            .split_once(',')
+            .ok_or_else(|| ParseError::new(s, "expected `x,y`"))?;
+        Ok(Dot {
+            x: parse_number_in(s, str_x)?,
+            y: parse_number_in(s, str_y)?,
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum FoldInstruction {
+    FoldAlongX(u64),
+    FoldAlongY(u64),
+}
+impl FromStr for FoldInstruction {
+    type Err = ParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some(("fold along y", v)) => Ok(FoldInstruction::FoldAlongY(parse_number_in(s, v)?)),
+            Some(("fold along x", v)) => Ok(FoldInstruction::FoldAlongX(parse_number_in(s, v)?)),
+            _ => Err(ParseError::new(
+                s,
+                "expected `fold along x=<n>` or `fold along y=<n>`",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    dots: Vec<Dot>,
+    fold_instructions: Vec<FoldInstruction>,
+}
+
+impl FromStr for Problem {
+    type Err = ParseError;
+
+    /// Dots, followed by an empty line and fold instructions
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut dots = vec![];
+        let mut fold_instructions = vec![];
+        let mut lines = s.lines().enumerate();
+        for (i, line) in lines.by_ref() {
+            if line.is_empty() {
+                break;
+            }
+            dots.push(line.parse::<Dot>().map_err(|err| err.at_line(i + 1))?);
+        }
+        for (i, line) in lines {
+            fold_instructions.push(
+                line.parse::<FoldInstruction>()
+                    .map_err(|err| err.at_line(i + 1))?,
+            );
+        }
+
+        if fold_instructions.is_empty() {
+            return Err(ParseError::new(s, "expected at least one fold instruction"));
+        }
+
+        Ok(Problem {
+            dots,
+            fold_instructions,
+        })
+    }
+}
+
+fn fold(dots: &[Dot], fold_instruction: FoldInstruction) -> Vec<Dot> {
+    let mut folded_dots: Vec<Dot> = match fold_instruction {
+        FoldInstruction::FoldAlongX(fold_line_x) => dots
+            .iter()
+            .map(|dot| Dot {
+                x: if dot.x < fold_line_x {
+                    dot.x
+                } else {
+                    2 * fold_line_x - dot.x
+                },
+                y: dot.y,
+            })
+            .collect(),
+        FoldInstruction::FoldAlongY(fold_line_y) => dots
+            .iter()
+            .map(|dot| Dot {
+                x: dot.x,
+                y: if dot.y < fold_line_y {
+                    dot.y
+                } else {
+                    2 * fold_line_y - dot.y
+                },
+            })
+            .collect(),
+    };
+    folded_dots.sort();
+    folded_dots.dedup();
+    folded_dots
+}
+
+/// Paper just large enough for the dots, `true` for a dot
+fn paper(dots: &[Dot]) -> Grid<bool> {
+    let width = dots.iter().map(|dot| dot.x + 1).max().unwrap_or(1);
+    let height = dots.iter().map(|dot| dot.y + 1).max().unwrap_or(1);
+    let mut paper = Grid::filled(width as usize, height as usize, false);
+    for dot in dots {
+        paper[(dot.x as usize, dot.y as usize)] = true;
+    }
+    paper
+}
+
+/// Paper after a number of folds
+pub struct PaperFrame {
+    fold_count: usize,
+    paper: Grid<bool>,
+}
+
+impl Frame for PaperFrame {
+    fn render(&self) -> String {
+        let paper = self.paper.render(|&dot| if dot { '#' } else { '.' });
+        format!("Folds: {}\n{}", self.fold_count, paper)
+    }
+}
+
+impl ToImage for PaperFrame {
+    fn to_image(&self) -> Image {
+        self.paper.to_image()
+    }
+}
+
+/// Unfolded paper and the paper after each fold
+pub fn frames(problem: &Problem) -> impl Iterator<Item = PaperFrame> + '_ {
+    let mut dots = problem.dots.clone();
+    (0..=problem.fold_instructions.len()).map(move |fold_count| {
+        if fold_count > 0 {
+            dots = fold(&dots, problem.fold_instructions[fold_count - 1]);
+        }
+        PaperFrame {
+            fold_count,
+            paper: paper(&dots),
+        }
+    })
+}
+
+pub struct TransparentOrigami;
+
+impl Solution for TransparentOrigami {
+    const DAY: u8 = 13;
+
+    type Input = Problem;
+    type Answer1 = usize;
+    type Answer2 = String;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        input.parse::<Problem>()
+    }
+
+    /// Remaining dots after one fold
+    fn part_1(&self, problem: &Self::Input) -> Self::Answer1 {
+        fold(&problem.dots, problem.fold_instructions[0]).len()
+    }
+
+    /// Paper after done folding
+    fn part_2(&self, problem: &Self::Input) -> Self::Answer2 {
+        let dots = problem
+            .fold_instructions
+            .iter()
+            .fold(problem.dots.clone(), |dots, &fold_instruction| {
+                fold(&dots, fold_instruction)
+            });
+
+        paper(&dots).render(|&dot| if dot { '#' } else { '.' })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn frames_of_folds() {
+        let input = fs::read_to_string("test_inputs/day_13_transparent_origami.txt").unwrap();
+        let problem = TransparentOrigami.parse(&input).unwrap();
+        let frames: Vec<PaperFrame> = frames(&problem).collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(
+            (frames[0].paper.width(), frames[0].paper.height()),
+            (11, 15)
+        );
+        assert_eq!(
+            frames[2].render(),
+            format!("Folds: 2\n{}", TransparentOrigami.part_2(&problem))
+        );
+    }
+}
@@ -0,0 +1,271 @@
+//! Solution to an Advent of Code problem, day 14, 2021
+//! https://adventofcode.com/2021/day/14
+use crate::error::ParseError;
+use crate::output::Count;
+use crate::Solution;
+use std::fmt::Display;
+
+fn difference_between_most_and_least_common_element(
+    pair_frequencies: [[u64; 256]; 256],
+) -> Option<u64> {
+    let mut min = u64::MAX;
+    let mut max = u64::MIN;
+
+    let mut character_frequencies: [u64; 256] = [0; 256];
+    for left in 0..256 {
+        for right in 0..256 {
+            character_frequencies[right as usize] = character_frequencies[right as usize]
+                .checked_add(pair_frequencies[left as usize][right as usize])?;
+        }
+    }
+
+    for f in character_frequencies {
+        if f < min && f > 0 {
+            min = f;
+        }
+        if f > max {
+            max = f;
+        }
+    }
+
+    Some(max - min)
+}
+
+fn to_pair_frequencies(polymer_template: &[u8]) -> [[u64; 256]; 256] {
+    let mut result = [[0; 256]; 256];
+    for i in 0..(polymer_template.len() - 1) {
+        let left = polymer_template[i];
+        let right = polymer_template[i + 1];
+        result[left as usize][right as usize] += 1;
+    }
+
+    // add a virtual pair [0][first character],
+    // because difference_between_most_and_least_common_element will only count right characters
+    result[0][polymer_template[0] as usize] = 1;
+
+    result
+}
+
+/// Runs a polymerization step according to insertion_rules, `None` if a frequency overflows
+fn polymerization_step(
+    pair_frequencies: [[u64; 256]; 256],
+    insertion_rules: [[Option<u8>; 256]; 256],
+) -> Option<[[u64; 256]; 256]> {
+    let mut result = [[0u64; 256]; 256];
+    for left in 0..256 {
+        for right in 0..256 {
+            if let Some(insertion) = insertion_rules[left][right] {
+                // Insertion of element 'insertion' between 'left' and 'right'
+                // results in two new character pairs: 'left', 'insertion' AND 'insertion', 'left'
+                result[left][insertion as usize] =
+                    result[left][insertion as usize].checked_add(pair_frequencies[left][right])?;
+                result[insertion as usize][right] =
+                    result[insertion as usize][right].checked_add(pair_frequencies[left][right])?;
+            } else {
+                // pairs without a rule stay, added to those produced by other insertions
+                result[left][right] =
+                    result[left][right].checked_add(pair_frequencies[left][right])?;
+            }
+        }
+    }
+
+    Some(result)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Polymerization {
+    polymer_template: Vec<u8>,
+    insertion_rules: [[Option<u8>; 256]; 256],
+}
+
+impl Display for Polymerization {
+    /// Template and rules, as in the input
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", String::from_utf8_lossy(&self.polymer_template))?;
+        writeln!(f)?;
+        for (left, rules) in self.insertion_rules.iter().enumerate() {
+            for (right, insertion) in rules.iter().enumerate() {
+                if let Some(insertion) = insertion {
+                    let (left, right) = (left as u8 as char, right as u8 as char);
+                    writeln!(f, "{}{} -> {}", left, right, *insertion as char)?;
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Difference in quantities of most and least common elements after given number of steps,
+/// `None` if the quantities overflow
+fn difference_after_steps(polymerization: &Polymerization, steps: usize) -> Option<u64> {
+    let mut pair_frequencies = to_pair_frequencies(&polymerization.polymer_template);
+
+    for _ in 0..steps {
+        pair_frequencies = polymerization_step(pair_frequencies, polymerization.insertion_rules)?;
+    }
+
+    difference_between_most_and_least_common_element(pair_frequencies)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ExtendedPolymerization {
+    /// Steps in part 1, at most `MAX_STEPS`
+    pub steps_part_1: usize,
+    /// Steps in part 2, at most `MAX_STEPS`
+    pub steps_part_2: usize,
+}
+
+impl ExtendedPolymerization {
+    /// Most steps which can be set in `config`, the quantities of the puzzle overflow after about 60
+    pub const MAX_STEPS: usize = 10_000;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: ExtendedPolymerization = ExtendedPolymerization {
+        steps_part_1: 10,
+        steps_part_2: 40,
+    };
+}
+
+impl Solution for ExtendedPolymerization {
+    const DAY: u8 = 14;
+
+    type Input = Polymerization;
+    type Answer1 = Count;
+    type Answer2 = Count;
+
+    /// Polymer template, an empty line and pair insertion rules, e.g. `CH -> B`
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let mut lines = input.lines();
+        let template_line = lines.next().unwrap_or("");
+        if template_line.is_empty() || !template_line.bytes().all(|c| c.is_ascii_uppercase()) {
+            return Err(
+                ParseError::new(template_line, "expected a polymer template, e.g. `NNCB`")
+                    .at_line(1),
+            );
+        }
+        let polymer_template = template_line.bytes().collect();
+
+        let mut insertion_rules: [[Option<u8>; 256]; 256] = [[None; 256]; 256];
+        for (i, line) in lines.enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            match line
+                .split_once(" -> ")
+                .map(|(pair, insert)| (pair.as_bytes(), insert.as_bytes()))
+            {
+                Some((&[left, right], &[insert]))
+                    if [left, right, insert].iter().all(u8::is_ascii_uppercase) =>
+                {
+                    insertion_rules[left as usize][right as usize] = Some(insert);
+                }
+                _ => {
+                    return Err(
+                        ParseError::new(line, "expected a rule, e.g. `CH -> B`").at_line(i + 2)
+                    )
+                }
+            }
+        }
+
+        Ok(Polymerization {
+            polymer_template,
+            insertion_rules,
+        })
+    }
+
+    /// Difference in quantities of most and least common elements after step `steps_part_1` (10)
+    fn part_1(&self, polymerization: &Self::Input) -> Self::Answer1 {
+        Count(difference_after_steps(polymerization, self.steps_part_1))
+    }
+
+    /// Difference in quantities of most and least common elements after step `steps_part_2` (40)
+    fn part_2(&self, polymerization: &Self::Input) -> Self::Answer2 {
+        Count(difference_after_steps(polymerization, self.steps_part_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+    use std::collections::HashMap;
+
+    /// Builds the polymer and counts its elements
+    fn brute_force_difference(input: &str, steps: usize) -> u64 {
+        let (template, rules) = input.split_once("\n\n").unwrap();
+        let rules: HashMap<&[u8], u8> = rules
+            .lines()
+            .map(|rule| (&rule.as_bytes()[..2], rule.as_bytes()[6]))
+            .collect();
+
+        let mut polymer = template.as_bytes().to_vec();
+        for _ in 0..steps {
+            let mut next = vec![polymer[0]];
+            for pair in polymer.windows(2) {
+                if let Some(&insertion) = rules.get(pair) {
+                    next.push(insertion);
+                }
+                next.push(pair[1]);
+            }
+            polymer = next;
+        }
+
+        let mut counts: HashMap<u8, u64> = HashMap::new();
+        for element in polymer {
+            *counts.entry(element).or_default() += 1;
+        }
+        counts.values().max().unwrap() - counts.values().min().unwrap()
+    }
+
+    #[test]
+    fn display_round_trips() {
+        let input = "NNCB\n\nCH -> B\nHH -> N\n";
+        let polymerization = ExtendedPolymerization::PUZZLE.parse(input).unwrap();
+        assert_eq!(polymerization.to_string(), input);
+        assert!(ExtendedPolymerization::PUZZLE
+            .parse("NNCB\n\nCH - B")
+            .is_err());
+        assert!(ExtendedPolymerization::PUZZLE
+            .parse("NNCB\n\nCH -> b")
+            .is_err());
+    }
+
+    #[test]
+    fn pair_frequencies_match_brute_force() {
+        let mut rng = Rng::new(14);
+        let elements = [b'B', b'C', b'H', b'N'];
+        for _ in 0..100 {
+            let template: String = (0..1 + rng.below(8))
+                .map(|_| *rng.choose(&elements) as char)
+                .collect();
+            let mut input = template + "\n\n";
+            for &left in &elements {
+                for &right in &elements {
+                    if rng.chance(0.7) {
+                        let insertion = *rng.choose(&elements);
+                        input += &format!(
+                            "{}{} -> {}\n",
+                            left as char, right as char, insertion as char
+                        );
+                    }
+                }
+            }
+
+            let polymerization = ExtendedPolymerization::PUZZLE.parse(&input).unwrap();
+            let steps = rng.below(11) as usize;
+            assert_eq!(
+                difference_after_steps(&polymerization, steps),
+                Some(brute_force_difference(&input, steps)),
+                "{} steps of\n{}",
+                steps,
+                input
+            );
+        }
+
+        let polymerization = ExtendedPolymerization::PUZZLE
+            .parse("NN\n\nNN -> N\n")
+            .unwrap();
+        assert_eq!(difference_after_steps(&polymerization, 63), Some(0));
+        assert_eq!(difference_after_steps(&polymerization, 64), None);
+    }
+}
@@ -0,0 +1,85 @@
+//! Solution to an Advent of Code problem, day 15, 2021
+//! https://adventofcode.com/2021/day/15
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::Solution;
+use std::cmp::min;
+
+/// Tiles the map `times` times in each direction, risk levels increase by 1 with each tile
+fn enlarge_map(risk_levels: &Grid<u32>, times: usize) -> Grid<u32> {
+    let size = risk_levels.width();
+    let mut enlarged_map = Grid::filled(times * size, times * size, 0);
+
+    for (x, y) in enlarged_map.points().collect::<Vec<(usize, usize)>>() {
+        let (i, j) = (x / size, y / size);
+        enlarged_map[(x, y)] =
+            (risk_levels[(x % size, y % size)] + (i as u32) + (j as u32) - 1) % 9 + 1;
+    }
+
+    enlarged_map
+}
+
+fn optimal_path_bellman_ford(map: &Grid<u32>) -> u32 {
+    let size = map.width();
+    let mut shortest_paths = Grid::filled(size, size, u32::MAX / 2);
+    shortest_paths[(0, 0)] = 0;
+
+    for _ in 0..size * size {
+        for point in map.points() {
+            for neighbor in map.neighbors_4(point) {
+                shortest_paths[point] =
+                    min(shortest_paths[point], map[point] + shortest_paths[neighbor]);
+            }
+        }
+    }
+
+    shortest_paths[(size - 1, size - 1)]
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chiton {
+    /// How many times the map is tiled in each direction in part 2, at most `MAX_TILING`
+    pub tiling: usize,
+}
+
+impl Chiton {
+    /// Largest tiling which can be set in `config`, the tiled map has tiling² times the risk levels
+    pub const MAX_TILING: usize = 10;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: Chiton = Chiton { tiling: 5 };
+}
+
+impl Solution for Chiton {
+    const DAY: u8 = 15;
+
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u32;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let risk_levels = Grid::parse(input, "a digit", |c| c.to_digit(10))?;
+        if risk_levels.width() != risk_levels.height() {
+            return Err(ParseError::new(
+                input.lines().next().unwrap_or(""),
+                format!(
+                    "expected a square map, found {} rows of {} risk levels",
+                    risk_levels.height(),
+                    risk_levels.width()
+                ),
+            ));
+        }
+        Ok(risk_levels)
+    }
+
+    /// Lowest total risk of any path from the top left to the bottom right
+    fn part_1(&self, risk_levels: &Self::Input) -> Self::Answer1 {
+        optimal_path_bellman_ford(risk_levels)
+    }
+
+    /// Lowest total risk on the map tiled `tiling` times (5) in each direction
+    fn part_2(&self, risk_levels: &Self::Input) -> Self::Answer2 {
+        let enlarged_map = enlarge_map(risk_levels, self.tiling);
+        optimal_path_bellman_ford(&enlarged_map)
+    }
+}
@@ -0,0 +1,415 @@
+//! Solution to an Advent of Code problem, day 16, 2021
+//! https://adventofcode.com/2021/day/16
+use crate::error::ParseError;
+use crate::Solution;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub enum Operation {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum Packet {
+    LiteralValue {
+        version: u64,
+        contents: u64,
+    },
+    Operator {
+        version: u64,
+        operation: Operation,
+        contents: Vec<Packet>,
+    },
+}
+
+fn iter_hex_to_bits<'a, I: std::iter::Iterator<Item = char> + 'a>(
+    i: I,
+) -> impl std::iter::Iterator<Item = bool> + 'a {
+    i.flat_map(|hexdec| {
+        let digit = hexdec.to_digit(16).unwrap_or(0);
+        [8, 4, 2, 1].map(|bit| digit & bit != 0)
+    })
+}
+
+fn try_parse_nbit_number<I: std::iter::Iterator<Item = bool>>(i: &mut I, n: usize) -> Option<u64> {
+    let (digits_count, number) = i.take(n).fold((0, 0), |(digits_count, number), bit| {
+        (digits_count + 1, number * 2 + bit as u64)
+    });
+
+    if digits_count == n {
+        Some(number)
+    } else {
+        None
+    }
+}
+
+fn try_parse_version<I: std::iter::Iterator<Item = bool>>(i: &mut I) -> Option<u64> {
+    try_parse_nbit_number(i, 3)
+}
+
+fn try_parse_type_id<I: std::iter::Iterator<Item = bool>>(i: &mut I) -> Option<u64> {
+    try_parse_nbit_number(i, 3)
+}
+
+fn try_parse_literal_packet_contents<I: std::iter::Iterator<Item = bool>>(
+    i: &mut I,
+) -> Option<u64> {
+    let mut ret = 0;
+    let mut continue_parsing = true;
+    while continue_parsing {
+        continue_parsing = i.next()?;
+        // values wider than 64 bits would be truncated
+        if ret >> 60 != 0 {
+            return None;
+        }
+        ret = (ret << 4) + try_parse_nbit_number(i, 4)?;
+    }
+    Some(ret)
+}
+
+fn try_parse_operator_packet_contents<I: std::iter::Iterator<Item = bool>>(
+    i: &mut I,
+) -> Option<Vec<Packet>> {
+    let mut result = vec![];
+
+    let length_type = i.next()?;
+    if length_type {
+        let number_of_subpackets = try_parse_nbit_number(i, 11)?;
+        for _ in 0..number_of_subpackets {
+            result.push(try_parse_packet(i)?);
+        }
+    } else {
+        let number_of_bits = try_parse_nbit_number(i, 15)?;
+        let mut bits_iter = i
+            .take(number_of_bits as usize)
+            // Without the following allocation Rust's type system explodes with recursive types
+            .collect::<Vec<bool>>()
+            .into_iter();
+        while let Some(packet) = try_parse_packet(&mut bits_iter) {
+            result.push(packet);
+        }
+    }
+
+    Some(result)
+}
+
+impl Operation {
+    fn type_id(&self) -> u64 {
+        match self {
+            Operation::Sum => 0,
+            Operation::Product => 1,
+            Operation::Minimum => 2,
+            Operation::Maximum => 3,
+            Operation::GreaterThan => 5,
+            Operation::LessThan => 6,
+            Operation::EqualTo => 7,
+        }
+    }
+}
+
+impl TryFrom<u64> for Operation {
+    type Error = ();
+
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Operation::Sum),
+            1 => Ok(Operation::Product),
+            2 => Ok(Operation::Minimum),
+            3 => Ok(Operation::Maximum),
+            5 => Ok(Operation::GreaterThan),
+            6 => Ok(Operation::LessThan),
+            7 => Ok(Operation::EqualTo),
+            _ => Err(()),
+        }
+    }
+}
+
+impl TryFrom<&str> for Packet {
+    type Error = ParseError;
+
+    /// Hexadecimal digits in upper or lower case
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        if let Some(i) = s.find(|c: char| !c.is_ascii_hexdigit()) {
+            return Err(ParseError::new(s, "expected a hexadecimal digit").at_column(i + 1));
+        }
+        try_parse_packet(&mut iter_hex_to_bits(s.chars()))
+            .ok_or_else(|| ParseError::new(s, "incomplete or invalid packet"))
+    }
+}
+
+fn try_parse_packet<I: std::iter::Iterator<Item = bool>>(i: &mut I) -> Option<Packet> {
+    let version = try_parse_version(i)?;
+    let type_id = try_parse_type_id(i)?;
+    match type_id {
+        4 => Some(Packet::LiteralValue {
+            version,
+            contents: try_parse_literal_packet_contents(i)?,
+        }),
+        _ => {
+            let operation = Operation::try_from(type_id).ok()?;
+            let contents = try_parse_operator_packet_contents(i)?;
+            // comparisons have two operands, other operations at least one
+            let operand_count_is_valid = match operation {
+                Operation::GreaterThan | Operation::LessThan | Operation::EqualTo => {
+                    contents.len() == 2
+                }
+                _ => !contents.is_empty(),
+            };
+            if !operand_count_is_valid {
+                return None;
+            }
+            Some(Packet::Operator {
+                version,
+                operation,
+                contents,
+            })
+        }
+    }
+}
+
+fn push_nbit_number(bits: &mut Vec<bool>, number: u64, n: usize) {
+    bits.extend((0..n).rev().map(|bit| (number >> bit) & 1 == 1));
+}
+
+impl Packet {
+    /// Inverse of try_parse_packet. Operators give the number of their subpackets if possible.
+    fn push_bits(&self, bits: &mut Vec<bool>) {
+        match self {
+            Packet::LiteralValue { version, contents } => {
+                push_nbit_number(bits, *version, 3);
+                push_nbit_number(bits, 4, 3);
+                let groups = (64 - contents.leading_zeros() as usize).div_ceil(4).max(1);
+                for group in (0..groups).rev() {
+                    bits.push(group > 0);
+                    push_nbit_number(bits, contents >> (4 * group), 4);
+                }
+            }
+            Packet::Operator {
+                version,
+                operation,
+                contents,
+            } => {
+                push_nbit_number(bits, *version, 3);
+                push_nbit_number(bits, operation.type_id(), 3);
+                let mut content_bits = vec![];
+                for packet in contents {
+                    packet.push_bits(&mut content_bits);
+                }
+                if contents.len() < 1 << 11 {
+                    bits.push(true);
+                    push_nbit_number(bits, contents.len() as u64, 11);
+                } else {
+                    bits.push(false);
+                    push_nbit_number(bits, content_bits.len() as u64, 15);
+                }
+                bits.extend(content_bits);
+            }
+        }
+    }
+
+    fn versions_sum(&self) -> u64 {
+        match self {
+            Packet::LiteralValue { version, .. } => *version,
+            Packet::Operator {
+                version, contents, ..
+            } => *version + contents.iter().map(Packet::versions_sum).sum::<u64>(),
+        }
+    }
+
+    fn evaluate(&self) -> u64 {
+        match self {
+            Packet::LiteralValue { contents, .. } => *contents,
+            Packet::Operator {
+                contents,
+                operation,
+                ..
+            } => {
+                let mut evaluated_contents = contents.iter().map(Packet::evaluate);
+                match operation {
+                    Operation::Sum => evaluated_contents.sum(),
+                    Operation::Product => evaluated_contents.product(),
+                    Operation::Minimum => evaluated_contents.min().unwrap(),
+                    Operation::Maximum => evaluated_contents.max().unwrap(),
+                    Operation::GreaterThan => {
+                        (evaluated_contents.next() > evaluated_contents.next()) as u64
+                    }
+                    Operation::LessThan => {
+                        (evaluated_contents.next() < evaluated_contents.next()) as u64
+                    }
+                    Operation::EqualTo => {
+                        (evaluated_contents.next() == evaluated_contents.next()) as u64
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl Display for Packet {
+    /// Hexadecimal transmission, padded with zeros
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut bits = vec![];
+        self.push_bits(&mut bits);
+        for digit in bits.chunks(4) {
+            let digit = digit
+                .iter()
+                .chain(std::iter::repeat(&false))
+                .take(4)
+                .fold(0, |digit, &bit| digit * 2 + bit as u32);
+            write!(f, "{:X}", digit)?;
+        }
+        Ok(())
+    }
+}
+
+pub struct PacketDecoder;
+
+impl Solution for PacketDecoder {
+    const DAY: u8 = 16;
+
+    type Input = Packet;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Packet::try_from(input.trim_end()).map_err(|err| err.at_line(1))
+    }
+
+    /// Packet version sum
+    fn part_1(&self, packet: &Self::Input) -> Self::Answer1 {
+        packet.versions_sum()
+    }
+
+    /// Evaluated result
+    fn part_2(&self, packet: &Self::Input) -> Self::Answer2 {
+        packet.evaluate()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_packet() {
+        assert_eq!(
+            Packet::try_from("D2FE28").unwrap(),
+            Packet::LiteralValue {
+                version: 6,
+                contents: 2021
+            }
+        );
+
+        assert_eq!(
+            Packet::try_from("8A004A801A8002F478").unwrap(),
+            Packet::Operator {
+                version: 4,
+                operation: Operation::Minimum,
+                contents: vec![Packet::Operator {
+                    version: 1,
+                    operation: Operation::Minimum,
+                    contents: vec![Packet::Operator {
+                        version: 5,
+                        operation: Operation::Minimum,
+                        contents: vec![Packet::LiteralValue {
+                            version: 6,
+                            contents: 15
+                        }]
+                    }]
+                }]
+            }
+        );
+    }
+
+    #[test]
+    fn test_packet_versions_sum() {
+        assert_eq!(Packet::try_from("D2FE28").unwrap().versions_sum(), 6);
+        assert_eq!(
+            Packet::try_from("8A004A801A8002F478")
+                .unwrap()
+                .versions_sum(),
+            16
+        );
+        assert_eq!(
+            Packet::try_from("620080001611562C8802118E34")
+                .unwrap()
+                .versions_sum(),
+            12
+        );
+        assert_eq!(
+            Packet::try_from("C0015000016115A2E0802F182340")
+                .unwrap()
+                .versions_sum(),
+            23
+        );
+        assert_eq!(
+            Packet::try_from("A0016C880162017C3686B18A3D4780")
+                .unwrap()
+                .versions_sum(),
+            31
+        );
+    }
+
+    #[test]
+    fn test_evaluate_packet() {
+        assert_eq!(Packet::try_from("C200B40A82").unwrap().evaluate(), 3);
+        assert_eq!(Packet::try_from("04005AC33890").unwrap().evaluate(), 54);
+        assert_eq!(Packet::try_from("880086C3E88112").unwrap().evaluate(), 7);
+        assert_eq!(Packet::try_from("CE00C43D881120").unwrap().evaluate(), 9);
+        assert_eq!(Packet::try_from("D8005AC2A8F0").unwrap().evaluate(), 1);
+        assert_eq!(Packet::try_from("F600BC2D8F").unwrap().evaluate(), 0);
+        assert_eq!(Packet::try_from("9C005AC2F8F0").unwrap().evaluate(), 0);
+        assert_eq!(
+            Packet::try_from("9C0141080250320F1802104A08")
+                .unwrap()
+                .evaluate(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(Packet::try_from("D2FX28").unwrap_err().column, Some(4));
+        assert!(Packet::try_from("D2").is_err());
+        assert_eq!(
+            Packet::try_from("d2fe28").unwrap(),
+            Packet::try_from("D2FE28").unwrap()
+        );
+        assert!(Packet::try_from("D2FE").is_err());
+        assert_eq!(
+            Packet::try_from("13FFFFFFFFFFFFFFFFFFBC").unwrap(),
+            Packet::LiteralValue {
+                version: 0,
+                contents: u64::MAX
+            }
+        );
+        // a literal of 68 bits
+        assert!(Packet::try_from("13FFFFFFFFFFFFFFFFFFFDE").is_err());
+        // minimum and less than without subpackets
+        assert!(Packet::try_from("4A000").is_err());
+        assert!(Packet::try_from("DA000").is_err());
+    }
+
+    #[test]
+    fn display_round_trips() {
+        for hex in [
+            "D2FE28",
+            "38006F45291200",
+            "EE00D40C823060",
+            "9C0141080250320F1802104A08",
+        ] {
+            let packet = Packet::try_from(hex).unwrap();
+            assert_eq!(
+                Packet::try_from(packet.to_string().as_str()).unwrap(),
+                packet
+            );
+        }
+        assert_eq!(Packet::try_from("D2FE28").unwrap().to_string(), "D2FE28");
+    }
+}
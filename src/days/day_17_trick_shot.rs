@@ -0,0 +1,300 @@
+//! Solution to an Advent of Code problem, day 17, 2021
+//! https://adventofcode.com/2021/day/17
+use crate::error::ParseError;
+use crate::Solution;
+use std::fmt::Display;
+
+#[derive(Debug, PartialEq)]
+pub struct TargetArea {
+    x1: i64,
+    x2: i64,
+    y1: i64,
+    y2: i64,
+}
+
+impl Display for TargetArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "target area: x={}..{}, y={}..{}",
+            self.x1, self.x2, self.y1, self.y2
+        )
+    }
+}
+
+#[derive(Debug)]
+struct ProbeState {
+    x: i64,
+    y: i64,
+    vx: i64,
+    vy: i64,
+}
+
+fn step(initial_probe_state: ProbeState) -> ProbeState {
+    ProbeState {
+        x: initial_probe_state.x + initial_probe_state.vx,
+        y: initial_probe_state.y + initial_probe_state.vy,
+        vx: match initial_probe_state.vx {
+            vx if vx > 0 => vx - 1,
+            vx if vx < 0 => vx + 1,
+            _ => 0,
+        },
+        vy: initial_probe_state.vy - 1,
+    }
+}
+
+fn is_in_target_area(target_area: &TargetArea, probe_state: &ProbeState) -> bool {
+    target_area.x1 <= probe_state.x
+        && probe_state.x <= target_area.x2
+        && target_area.y1 <= probe_state.y
+        && probe_state.y <= target_area.y2
+}
+
+fn try_find_max_y_if_hit(target_area: &TargetArea, initial_vxvy: (i64, i64)) -> Option<i64> {
+    let mut max_y = 0;
+    let mut probe_state = ProbeState {
+        x: 0,
+        y: 0,
+        vx: initial_vxvy.0,
+        vy: initial_vxvy.1,
+    };
+
+    // when probe is falling and below target area, hope is lost
+    while !(probe_state.vy < 0 && probe_state.y < target_area.y1) {
+        if is_in_target_area(target_area, &probe_state) {
+            return Some(max_y);
+        }
+
+        probe_state = step(probe_state);
+        max_y = std::cmp::max(max_y, probe_state.y);
+    }
+
+    None
+}
+
+/// example:
+/// target area: x=20..30, y=-10..-5
+fn parse_target_area(s: &str) -> Option<TargetArea> {
+    let (_, ranges) = s.split_once(": ")?;
+    let (xx_range, yy_range) = ranges.split_once(", ")?;
+    let (_, x_range) = xx_range.split_once('=')?;
+    let (_, y_range) = yy_range.split_once('=')?;
+    let (x1, x2) = x_range.split_once("..")?;
+    let (y1, y2) = y_range.split_once("..")?;
+    Some(TargetArea {
+        x1: x1.parse().ok()?,
+        x2: x2.parse().ok()?,
+        y1: y1.parse().ok()?,
+        y2: y2.parse().ok()?,
+    })
+}
+
+fn initial_velocities(target_area: &TargetArea) -> impl std::iter::Iterator<Item = (i64, i64)> {
+    // lower vx bound: In test data, the target area is always in the positive x direction
+    // upper vx bound: If vx > target_area.x2, probe will go beyond target area in first step
+    // lower vy bound: If vy < target_area.y1, probe will go below target area in first step
+    // upper vy bound: For positive initial vy, the probe will always come again to position y=0 with vy=-initial vy.
+    //                 For initial vy > -target_area.y1, the probe will go below target area in one step after that.
+    let x_range = 0..target_area.x2 + 1;
+    let y_range = target_area.y1..-target_area.y1;
+    x_range
+        .into_iter()
+        .flat_map(move |x| y_range.clone().map(move |y| (x, y)))
+}
+
+fn optimize_initial_vx_vy_for_max_y(target_area: &TargetArea) -> i64 {
+    initial_velocities(target_area)
+        .filter_map(|initial_vxvy| try_find_max_y_if_hit(target_area, initial_vxvy))
+        .max()
+        .unwrap()
+}
+
+fn count_distinct_vx_vy_that_hit(target_area: &TargetArea) -> usize {
+    initial_velocities(target_area)
+        .filter_map(|initial_vxvy| try_find_max_y_if_hit(target_area, initial_vxvy))
+        .count()
+}
+
+pub struct TrickShot;
+
+impl Solution for TrickShot {
+    const DAY: u8 = 17;
+
+    type Input = TargetArea;
+    type Answer1 = i64;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.lines().next().unwrap_or("");
+        let target_area = parse_target_area(line).ok_or_else(|| {
+            ParseError::new(line, "expected `target area: x=<x1>..<x2>, y=<y1>..<y2>`").at_line(1)
+        })?;
+        if target_area.x1 > target_area.x2 || target_area.y1 > target_area.y2 {
+            return Err(ParseError::new(line, "expected ranges from low to high").at_line(1));
+        }
+        // see initial_velocities
+        if target_area.x1 < 0 || target_area.y2 >= 0 {
+            return Err(ParseError::new(
+                line,
+                "expected target area in the positive x and negative y direction",
+            )
+            .at_line(1));
+        }
+        Ok(target_area)
+    }
+
+    /// Max height reached, while hitting the target
+    fn part_1(&self, target_area: &Self::Input) -> Self::Answer1 {
+        optimize_initial_vx_vy_for_max_y(target_area)
+    }
+
+    /// Count of distinct initial (vx, vy), that hit the target
+    fn part_2(&self, target_area: &Self::Input) -> Self::Answer2 {
+        count_distinct_vx_vy_that_hit(target_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_parse_target_area() {
+        assert_eq!(
+            parse_target_area("target area: x=20..30, y=-10..-5").unwrap(),
+            TargetArea {
+                x1: 20,
+                x2: 30,
+                y1: -10,
+                y2: -5
+            }
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert!(TrickShot.parse("target area: x=20..30, y=-10..").is_err());
+        assert!(TrickShot.parse("target area: x=30..20, y=-10..-5").is_err());
+        assert!(TrickShot.parse("target area: x=20..30, y=-5..-10").is_err());
+        assert!(TrickShot
+            .parse("target area: x=-30..-20, y=-10..-5")
+            .is_err());
+
+        let line = "target area: x=20..30, y=-10..-5";
+        assert_eq!(TrickShot.parse(line).unwrap().to_string(), line);
+    }
+
+    #[test]
+    fn test_simulate() {
+        assert_eq!(
+            try_find_max_y_if_hit(
+                &TargetArea {
+                    x1: 20,
+                    x2: 30,
+                    y1: -10,
+                    y2: -5
+                },
+                (7, 2)
+            ),
+            Some(3)
+        );
+        assert_eq!(
+            try_find_max_y_if_hit(
+                &TargetArea {
+                    x1: 20,
+                    x2: 30,
+                    y1: -10,
+                    y2: -5
+                },
+                (17, -4)
+            ),
+            None
+        );
+        assert_eq!(
+            try_find_max_y_if_hit(
+                &TargetArea {
+                    x1: 20,
+                    x2: 30,
+                    y1: -10,
+                    y2: -5
+                },
+                (6, 9)
+            ),
+            Some(45)
+        );
+    }
+
+    #[test]
+    fn test_optimize_initial_vx_vy_for_max_y() {
+        assert_eq!(
+            optimize_initial_vx_vy_for_max_y(&TargetArea {
+                x1: 20,
+                x2: 30,
+                y1: -10,
+                y2: -5
+            }),
+            45
+        )
+    }
+    #[test]
+    fn test_count_distinct_vx_vy_that_hit() {
+        assert_eq!(
+            count_distinct_vx_vy_that_hit(&TargetArea {
+                x1: 20,
+                x2: 30,
+                y1: -10,
+                y2: -5
+            }),
+            112
+        )
+    }
+
+    /// Simulates a wide range of velocities for long enough to see every hit
+    fn brute_force_hits(target_area: &TargetArea) -> HashMap<(i64, i64), i64> {
+        let margin = 20;
+        let steps = 4 * (margin - target_area.y1);
+        let mut hits = HashMap::new();
+        for initial_vx in -margin..=target_area.x2 + margin {
+            for initial_vy in target_area.y1 - margin..=margin - target_area.y1 {
+                let (mut x, mut y, mut vx, mut vy) = (0, 0, initial_vx, initial_vy);
+                let mut max_y = 0;
+                for _ in 0..steps {
+                    let in_target_area = (target_area.x1..=target_area.x2).contains(&x)
+                        && (target_area.y1..=target_area.y2).contains(&y);
+                    if in_target_area {
+                        hits.insert((initial_vx, initial_vy), max_y);
+                        break;
+                    }
+                    x += vx;
+                    y += vy;
+                    vx -= vx.signum();
+                    vy -= 1;
+                    max_y = max_y.max(y);
+                }
+            }
+        }
+        hits
+    }
+
+    #[test]
+    fn initial_velocities_include_all_hits() {
+        let mut rng = Rng::new(17);
+        for _ in 0..100 {
+            let x1 = rng.range(0..=30);
+            let y2 = rng.range(-30..=-1);
+            let target_area = TargetArea {
+                x1,
+                x2: x1 + rng.range(0..=15),
+                y1: y2 - rng.range(0..=15),
+                y2,
+            };
+
+            let hits: HashMap<(i64, i64), i64> = initial_velocities(&target_area)
+                .filter_map(|vxvy| try_find_max_y_if_hit(&target_area, vxvy).map(|y| (vxvy, y)))
+                .collect();
+            assert_eq!(hits, brute_force_hits(&target_area), "{:?}", target_area);
+        }
+    }
+}
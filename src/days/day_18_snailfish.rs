@@ -0,0 +1,446 @@
+//! Solution to an Advent of Code problem, day 18, 2021
+//! https://adventofcode.com/2021/day/18
+use crate::error::{parse_lines, ParseError};
+use crate::Solution;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Copy, Clone)]
+enum Token {
+    Open,
+    Close,
+    Next,
+    Number(u64),
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct SnailfishNumber {
+    tokens: Vec<Token>,
+}
+
+/// Index after the element starting at `start`: a number, or a pair of two elements inside
+/// at most 4 other pairs, which reduce() can explode. `depth` counts the enclosing pairs.
+fn element_end(tokens: &[Token], start: usize, depth: usize) -> Option<usize> {
+    match tokens.get(start)? {
+        Token::Number(_) => Some(start + 1),
+        Token::Open if depth <= 4 => {
+            let next = element_end(tokens, start + 1, depth + 1)?;
+            if tokens.get(next)? != &Token::Next {
+                return None;
+            }
+            let close = element_end(tokens, next + 1, depth + 1)?;
+            (tokens.get(close)? == &Token::Close).then_some(close + 1)
+        }
+        _ => None,
+    }
+}
+
+impl FromStr for SnailfishNumber {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let tokens = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '[' => Ok(Token::Open),
+                ']' => Ok(Token::Close),
+                ',' => Ok(Token::Next),
+                n => n
+                    .to_digit(10)
+                    .map(|n| Token::Number(n as u64))
+                    .ok_or_else(|| {
+                        ParseError::new(s, "expected `[`, `]`, `,` or a digit").at_column(i + 1)
+                    }),
+            })
+            .collect::<Result<Vec<Token>, ParseError>>()?;
+
+        // a single pair, reduce() cannot explode pairs nested deeper
+        if tokens.first() != Some(&Token::Open) || element_end(&tokens, 0, 0) != Some(tokens.len())
+        {
+            return Err(ParseError::new(
+                s,
+                "expected a pair of two elements, nested at most 5 pairs deep, e.g. `[[1,2],3]`",
+            ));
+        }
+
+        Ok(SnailfishNumber { tokens })
+    }
+}
+
+impl Display for SnailfishNumber {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::result::Result<(), std::fmt::Error> {
+        for token in self.tokens.iter() {
+            match token {
+                Token::Open => write!(f, "[")?,
+                Token::Close => write!(f, "]")?,
+                Token::Next => write!(f, ",")?,
+                Token::Number(n) => write!(f, "{}", n)?,
+            };
+        }
+
+        Ok(())
+    }
+}
+
+impl SnailfishNumber {
+    /// Max count of nested pairs
+    fn depth(&self) -> usize {
+        self.tokens
+            .iter()
+            .scan(0, |depth, token| {
+                match token {
+                    Token::Open => *depth += 1,
+                    Token::Close => *depth -= 1,
+                    _ => {}
+                }
+                Some(*depth)
+            })
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn magnitude(&self) -> u64 {
+        self.tokens
+            .iter()
+            .fold((0, 1), |(sum, factor), token| match token {
+                Token::Open => (sum, factor * 3),
+                Token::Next => (sum, factor / 3 * 2),
+                Token::Close => (sum, factor / 2),
+                Token::Number(n) => (sum + factor * n, factor),
+            })
+            .0
+    }
+
+    fn reduce(&mut self) -> &mut Self {
+        loop {
+            // explode?
+            if let Some(exploding_pair_start) = self
+                .tokens
+                .iter()
+                .scan(0, |nesting_level, &token| {
+                    match token {
+                        Token::Open => *nesting_level += 1,
+                        Token::Close => *nesting_level -= 1,
+                        _ => {}
+                    };
+                    Some(*nesting_level)
+                })
+                .position(|nesting_level| nesting_level == 5)
+            {
+                let exploding_pair_end = exploding_pair_start + 4; // Number, Next, Number, Close
+                if let (Token::Number(left_number), Token::Number(right_number)) = (
+                    self.tokens[exploding_pair_start + 1],
+                    self.tokens[exploding_pair_end - 1],
+                ) {
+                    for token in self.tokens[..exploding_pair_start].iter_mut().rev() {
+                        if let Token::Number(first_number_to_the_left) = token {
+                            *first_number_to_the_left += left_number;
+                            break;
+                        }
+                    }
+                    for token in self.tokens[exploding_pair_end..].iter_mut() {
+                        if let Token::Number(first_number_to_the_right) = token {
+                            *first_number_to_the_right += right_number;
+                            break;
+                        }
+                    }
+                    self.tokens.splice(
+                        exploding_pair_start..exploding_pair_end + 1,
+                        std::iter::once(Token::Number(0)),
+                    );
+                }
+
+                continue;
+            }
+
+            // split?
+            if let Some((number_to_split_index, Token::Number(number_to_split))) = self
+                .tokens
+                .iter()
+                .enumerate()
+                .find(|(_, &token)| matches!(token, Token::Number(n) if n >= 10))
+            {
+                let l = number_to_split / 2;
+                let r = number_to_split - l;
+                self.tokens.splice(
+                    number_to_split_index..number_to_split_index + 1,
+                    [
+                        Token::Open,
+                        Token::Number(l),
+                        Token::Next,
+                        Token::Number(r),
+                        Token::Close,
+                    ],
+                );
+                continue;
+            }
+
+            break;
+        }
+        self
+    }
+
+    fn add(&self, other: &SnailfishNumber) -> Self {
+        let mut new_tokens = vec![Token::Open];
+        new_tokens.extend(&self.tokens);
+        new_tokens.push(Token::Next);
+        new_tokens.extend(&other.tokens);
+        new_tokens.push(Token::Close);
+
+        let mut new_number = SnailfishNumber { tokens: new_tokens };
+        new_number.reduce();
+        new_number
+    }
+}
+
+pub struct Snailfish;
+
+impl Solution for Snailfish {
+    const DAY: u8 = 18;
+
+    type Input = Vec<SnailfishNumber>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let numbers = parse_lines(input, |line| {
+            let number = SnailfishNumber::from_str(line)?;
+            // sums of deeper numbers have pairs that reduce() cannot explode
+            if number.depth() > 4 {
+                return Err(ParseError::new(
+                    line,
+                    "expected a reduced number, nested at most 4 pairs deep",
+                ));
+            }
+            Ok(number)
+        })?;
+        // part 2 adds two different numbers
+        if numbers.len() < 2 {
+            return Err(ParseError::new(input, "expected at least two numbers"));
+        }
+        Ok(numbers)
+    }
+
+    /// Magnitude of the sum
+    fn part_1(&self, numbers: &Self::Input) -> Self::Answer1 {
+        let sum = numbers
+            .iter()
+            .cloned()
+            .reduce(|left, right| left.add(&right))
+            .unwrap();
+
+        sum.magnitude()
+    }
+
+    /// Max magnitude of a pair of added numbers
+    fn part_2(&self, numbers: &Self::Input) -> Self::Answer2 {
+        (0..numbers.len())
+            .flat_map(|i| (0..numbers.len()).map(move |j| (i, j)))
+            .filter(|(i, j)| i != j)
+            .map(|(i, j)| numbers[i].add(&numbers[j]).magnitude())
+            .max()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            SnailfishNumber {
+                tokens: vec![
+                    Token::Open,
+                    Token::Number(1),
+                    Token::Next,
+                    Token::Number(2),
+                    Token::Close
+                ]
+            },
+            SnailfishNumber::from_str("[1,2]").unwrap()
+        );
+        assert_eq!(
+            SnailfishNumber {
+                tokens: vec![
+                    Token::Open,
+                    Token::Open,
+                    Token::Open,
+                    Token::Open,
+                    Token::Number(1),
+                    Token::Next,
+                    Token::Number(2),
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Number(3),
+                    Token::Next,
+                    Token::Number(4),
+                    Token::Close,
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Open,
+                    Token::Number(5),
+                    Token::Next,
+                    Token::Number(6),
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Number(7),
+                    Token::Next,
+                    Token::Number(8),
+                    Token::Close,
+                    Token::Close,
+                    Token::Close,
+                    Token::Next,
+                    Token::Number(9),
+                    Token::Close
+                ]
+            },
+            SnailfishNumber::from_str("[[[[1,2],[3,4]],[[5,6],[7,8]]],9]").unwrap()
+        );
+    }
+
+    #[test]
+    fn fmt() {
+        assert_eq!(
+            "[[[[1,2],[3,4]],[[5,6],[7,8]]],9]",
+            SnailfishNumber {
+                tokens: vec![
+                    Token::Open,
+                    Token::Open,
+                    Token::Open,
+                    Token::Open,
+                    Token::Number(1),
+                    Token::Next,
+                    Token::Number(2),
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Number(3),
+                    Token::Next,
+                    Token::Number(4),
+                    Token::Close,
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Open,
+                    Token::Number(5),
+                    Token::Next,
+                    Token::Number(6),
+                    Token::Close,
+                    Token::Next,
+                    Token::Open,
+                    Token::Number(7),
+                    Token::Next,
+                    Token::Number(8),
+                    Token::Close,
+                    Token::Close,
+                    Token::Close,
+                    Token::Next,
+                    Token::Number(9),
+                    Token::Close
+                ]
+            }
+            .to_string(),
+        );
+    }
+
+    #[test]
+    fn magnitude() {
+        assert_eq!(29, SnailfishNumber::from_str("[9,1]").unwrap().magnitude());
+        assert_eq!(
+            129,
+            SnailfishNumber::from_str("[[9,1],[1,9]]")
+                .unwrap()
+                .magnitude()
+        );
+        assert_eq!(
+            143,
+            SnailfishNumber::from_str("[[1,2],[[3,4],5]]")
+                .unwrap()
+                .magnitude()
+        );
+        assert_eq!(
+            1384,
+            SnailfishNumber::from_str("[[[[0,7],4],[[7,8],[6,0]]],[8,1]]")
+                .unwrap()
+                .magnitude()
+        );
+        assert_eq!(
+            3488,
+            SnailfishNumber::from_str("[[[[8,7],[7,7]],[[8,6],[7,7]]],[[[0,7],[6,6]],[8,7]]]")
+                .unwrap()
+                .magnitude()
+        );
+    }
+
+    #[test]
+    fn reduce() {
+        assert_eq!(
+            "[[[[0,9],2],3],4]",
+            SnailfishNumber::from_str("[[[[[9,8],1],2],3],4]")
+                .unwrap()
+                .reduce()
+                .to_string()
+        );
+        assert_eq!(
+            "[[[[0,7],4],[[7,8],[6,0]]],[8,1]]",
+            SnailfishNumber::from_str("[[[[[4,3],4],4],[7,[[8,4],9]]],[1,1]]")
+                .unwrap()
+                .reduce()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            "[[[[1,1],[2,2]],[3,3]],[4,4]]",
+            SnailfishNumber::from_str("[1,1]")
+                .unwrap()
+                .add(&SnailfishNumber::from_str("[2,2]").unwrap())
+                .add(&SnailfishNumber::from_str("[3,3]").unwrap())
+                .add(&SnailfishNumber::from_str("[4,4]").unwrap())
+                .to_string()
+        );
+        assert_eq!(
+            "[[[[5,0],[7,4]],[5,5]],[6,6]]",
+            SnailfishNumber::from_str("[1,1]")
+                .unwrap()
+                .add(&SnailfishNumber::from_str("[2,2]").unwrap())
+                .add(&SnailfishNumber::from_str("[3,3]").unwrap())
+                .add(&SnailfishNumber::from_str("[4,4]").unwrap())
+                .add(&SnailfishNumber::from_str("[5,5]").unwrap())
+                .add(&SnailfishNumber::from_str("[6,6]").unwrap())
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn parse_errors() {
+        assert_eq!(
+            SnailfishNumber::from_str("[1,x]").unwrap_err().column,
+            Some(4)
+        );
+        assert!(SnailfishNumber::from_str("[1,2]]").is_err());
+        assert!(SnailfishNumber::from_str("[[1,2]").is_err());
+        assert!(SnailfishNumber::from_str("[1,2][3,4]").is_err());
+        assert!(SnailfishNumber::from_str("[]").is_err());
+        assert!(SnailfishNumber::from_str("[1,2,3]").is_err());
+        assert!(SnailfishNumber::from_str("[[1],2]").is_err());
+        assert!(SnailfishNumber::from_str("7").is_err());
+        assert!(SnailfishNumber::from_str("[[[[[1,2],3],4],5],6]").is_ok());
+        // reduce() would loop forever
+        assert!(SnailfishNumber::from_str("[[[[[[1,2],3],4],5],6],7]").is_err());
+        assert!(Snailfish.parse("[[1,2],3]\n[[[[[1,2],3],4],5],6]").is_err());
+        assert!(Snailfish.parse("").is_err());
+        assert_eq!(
+            Snailfish.parse("[1,2]").unwrap_err().to_string(),
+            "expected at least two numbers (`[1,2]`)"
+        );
+    }
+}
@@ -0,0 +1,193 @@
+//! Solution to an Advent of Code problem, day 1, 2021
+//! https://adventofcode.com/2021/day/1
+use crate::error::{parse_lines, parse_number, ParseError};
+use crate::Solution;
+use std::cmp::{max, Ordering};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Statistics of a series of depths, compared window by window
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepReport {
+    pub window: usize,
+    /// Depths read, including those of incomplete windows
+    pub depths: u64,
+    /// Windows deeper than the previous window
+    pub increases: u64,
+    /// Windows shallower than the previous window
+    pub decreases: u64,
+    /// Windows as deep as the previous window
+    pub plateaus: u64,
+    /// Most increases in a row
+    pub longest_increasing_run: u64,
+}
+
+impl fmt::Display for SweepReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "depths: {}", self.depths)?;
+        writeln!(f, "window: {}", self.window)?;
+        writeln!(f, "increases: {}", self.increases)?;
+        writeln!(f, "decreases: {}", self.decreases)?;
+        writeln!(f, "plateaus: {}", self.plateaus)?;
+        writeln!(f, "longest increasing run: {}", self.longest_increasing_run)
+    }
+}
+
+/// Sliding window analytics of a stream of depths, computed in one pass.
+/// Only the depths of the current window are kept, so the stream may be unbounded.
+/// Windows are compared by their sums, which compare like their averages. Sums are kept
+/// in 128 bits, wide enough for any window of 64 bit depths.
+pub struct SweepAnalytics {
+    window: VecDeque<i64>,
+    sum: i128,
+    previous_sum: Option<i128>,
+    run: u64,
+    report: SweepReport,
+}
+
+impl SweepAnalytics {
+    /// Analytics of windows of `window` depths, at least 1
+    pub fn new(window: usize) -> SweepAnalytics {
+        assert!(window > 0, "Windows must hold at least one depth");
+        SweepAnalytics {
+            window: VecDeque::with_capacity(window + 1),
+            sum: 0,
+            previous_sum: None,
+            run: 0,
+            report: SweepReport {
+                window,
+                depths: 0,
+                increases: 0,
+                decreases: 0,
+                plateaus: 0,
+                longest_increasing_run: 0,
+            },
+        }
+    }
+
+    /// Adds the next depth. Returns the moving average of the last `window` depths,
+    /// None until there are enough depths.
+    pub fn push(&mut self, depth: i64) -> Option<f64> {
+        self.report.depths += 1;
+        self.window.push_back(depth);
+        self.sum += depth as i128;
+        if self.window.len() > self.report.window {
+            self.sum -= self.window.pop_front().unwrap_or_default() as i128;
+        }
+        if self.window.len() < self.report.window {
+            return None;
+        }
+
+        if let Some(previous_sum) = self.previous_sum {
+            match self.sum.cmp(&previous_sum) {
+                Ordering::Greater => {
+                    self.report.increases += 1;
+                    self.run += 1;
+                    self.report.longest_increasing_run =
+                        max(self.report.longest_increasing_run, self.run);
+                }
+                Ordering::Less => {
+                    self.report.decreases += 1;
+                    self.run = 0;
+                }
+                Ordering::Equal => {
+                    self.report.plateaus += 1;
+                    self.run = 0;
+                }
+            }
+        }
+        self.previous_sum = Some(self.sum);
+        Some(self.sum as f64 / self.report.window as f64)
+    }
+
+    /// Statistics of the depths added so far
+    pub fn report(&self) -> &SweepReport {
+        &self.report
+    }
+}
+
+/// Statistics of all depths, compared in windows of `window` depths
+pub fn analyze<I: Iterator<Item = i64>>(depths: I, window: usize) -> SweepReport {
+    let mut analytics = SweepAnalytics::new(window);
+    for depth in depths {
+        analytics.push(depth);
+    }
+    analytics.report
+}
+
+/// Moving averages of `window` depths, one per complete window
+pub fn moving_averages<I: Iterator<Item = i64>>(
+    depths: I,
+    window: usize,
+) -> impl Iterator<Item = f64> {
+    let mut analytics = SweepAnalytics::new(window);
+    depths.filter_map(move |depth| analytics.push(depth))
+}
+
+pub struct SonarSweep;
+
+impl Solution for SonarSweep {
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_number::<i64>)
+    }
+
+    /// Times water got deeper
+    fn part_1(&self, numbers: &Self::Input) -> Self::Answer1 {
+        analyze(numbers.iter().copied(), 1).increases
+    }
+
+    /// Times water got deeper (averaged depths)
+    fn part_2(&self, numbers: &Self::Input) -> Self::Answer2 {
+        analyze(numbers.iter().copied(), 3).increases
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [i64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn windows() {
+        assert_eq!(
+            analyze(DEPTHS.into_iter(), 3),
+            SweepReport {
+                window: 3,
+                depths: 10,
+                increases: 5,
+                decreases: 1,
+                plateaus: 1,
+                longest_increasing_run: 4,
+            }
+        );
+        let report = analyze(DEPTHS.into_iter(), 1);
+        assert_eq!((report.increases, report.decreases), (7, 2));
+        let report = analyze(DEPTHS.into_iter(), 20);
+        assert_eq!((report.depths, report.increases), (10, 0));
+
+        // sums of extreme depths do not overflow
+        let report = analyze([i64::MAX, i64::MAX, i64::MAX, 0, i64::MIN].into_iter(), 3);
+        assert_eq!((report.increases, report.decreases), (0, 2));
+        let mut analytics = SweepAnalytics::new(2);
+        analytics.push(i64::MAX);
+        assert_eq!(analytics.push(i64::MAX), Some(i64::MAX as f64));
+    }
+
+    #[test]
+    fn moving_averages_stream() {
+        // not `Clone`, depths are only seen once
+        let mut depths = DEPTHS.into_iter();
+        let stream = std::iter::from_fn(|| depths.next());
+        let averages: Vec<f64> = moving_averages(stream, 5).collect();
+        assert_eq!(averages.len(), 6);
+        assert_eq!(averages[0], 203.4);
+        assert_eq!(averages[5], 247.8);
+    }
+}
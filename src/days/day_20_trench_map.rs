@@ -0,0 +1,217 @@
+//! Solution to an Advent of Code problem, day 20, 2021
+//! https://adventofcode.com/2021/day/20
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::image::{Image, ToImage};
+use crate::visualize::Frame;
+use crate::Solution;
+
+/// Row of `#` (light) and `.` (dark) pixels
+fn parse_pixels(line: &str) -> Result<Vec<bool>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| match c {
+            '#' => Ok(true),
+            '.' => Ok(false),
+            _ => Err(ParseError::new(line, "expected `#` or `.`").at_column(i + 1)),
+        })
+        .collect()
+}
+
+fn pad(image: &Grid<bool>, padding: usize) -> Grid<bool> {
+    let mut padded = Grid::filled(
+        image.width() + 2 * padding,
+        image.height() + 2 * padding,
+        false,
+    );
+    for (x, y) in image.points() {
+        padded[(x + padding, y + padding)] = image[(x, y)];
+    }
+
+    padded
+}
+
+/// Image without `margin` pixels on each side
+fn crop(image: &Grid<bool>, margin: usize) -> Grid<bool> {
+    let width = image.width() - 2 * margin;
+    let height = image.height() - 2 * margin;
+    let cells = (0..height)
+        .flat_map(|y| image.row(y + margin)[margin..margin + width].to_vec())
+        .collect();
+    Grid::new(width, height, cells)
+}
+
+fn enhance(algorithm: &[bool], original: &Grid<bool>) -> Grid<bool> {
+    let mut enhanced = original.clone();
+
+    for point in original.points() {
+        // 3x3 square around the point, row by row, is a binary number
+        let mut index = 0;
+        for dy in -1..=1 {
+            for dx in -1..=1 {
+                let lit = original
+                    .offset(point, (dx, dy))
+                    .is_some_and(|neighbor| original[neighbor]);
+                index = 2 * index + lit as usize;
+            }
+        }
+        enhanced[point] = algorithm[index];
+    }
+
+    enhanced
+}
+
+fn lit_pixel_count_after(algorithm: &[bool], image: &Grid<bool>, iterations: usize) -> usize {
+    // pad image to pretend it's infinite
+    let mut image = pad(image, iterations * 2);
+    for _ in 0..iterations {
+        image = enhance(algorithm, &image);
+    }
+
+    let w = image.width();
+    let h = image.height();
+
+    // points near the boundaries are garbage
+    // in theory, the image is infinite
+    let garbage = iterations - 1;
+    image
+        .points()
+        .filter(|&(x, y)| garbage <= x && x < w - garbage && garbage <= y && y < h - garbage)
+        .filter(|&point| image[point])
+        .count()
+}
+
+/// Image after an iteration of the enhancement
+pub struct TrenchMapFrame {
+    iteration: usize,
+    image: Grid<bool>,
+}
+
+impl Frame for TrenchMapFrame {
+    fn render(&self) -> String {
+        let lit_count = self.image.iter().filter(|&&lit| lit).count();
+        let image = self.image.render(|&lit| if lit { '#' } else { '.' });
+        format!(
+            "Iteration {}, {} lit pixels\n{}",
+            self.iteration, lit_count, image
+        )
+    }
+}
+
+impl ToImage for TrenchMapFrame {
+    fn to_image(&self) -> Image {
+        self.image.to_image()
+    }
+}
+
+/// Input image and the image after each of the iterations.
+/// Each iteration grows the image by a pixel on each side.
+pub fn frames(
+    input: &TrenchMapInput,
+    iterations: usize,
+) -> impl Iterator<Item = TrenchMapFrame> + '_ {
+    // as in `lit_pixel_count_after`, with garbage near the boundaries cropped away
+    let padding = iterations * 2;
+    let mut image = pad(&input.image, padding);
+    (0..=iterations).map(move |iteration| {
+        if iteration > 0 {
+            image = enhance(&input.algorithm, &image);
+        }
+        TrenchMapFrame {
+            iteration,
+            image: crop(&image, padding - iteration),
+        }
+    })
+}
+
+pub struct TrenchMapInput {
+    algorithm: Vec<bool>,
+    image: Grid<bool>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TrenchMap {
+    /// Iterations of the enhancement in part 1, from 1 to `MAX_ITERATIONS`
+    pub iterations_part_1: usize,
+    /// Iterations of the enhancement in part 2, from 1 to `MAX_ITERATIONS`
+    pub iterations_part_2: usize,
+}
+
+impl TrenchMap {
+    /// Most iterations which can be set in `config`, the image grows by 4 pixels with each
+    pub const MAX_ITERATIONS: usize = 200;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: TrenchMap = TrenchMap {
+        iterations_part_1: 2,
+        iterations_part_2: 50,
+    };
+}
+
+impl Solution for TrenchMap {
+    const DAY: u8 = 20;
+
+    type Input = TrenchMapInput;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    /// Image enhancement algorithm, an empty line and the input image
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let algorithm_line = input.lines().next().unwrap_or("");
+        let algorithm = parse_pixels(algorithm_line).map_err(|err| err.at_line(1))?;
+        if algorithm.len() != 512 {
+            return Err(ParseError::new(
+                algorithm_line,
+                format!("expected 512 pixels, found {}", algorithm.len()),
+            )
+            .at_line(1));
+        }
+
+        // the image starts after an empty line
+        let image_start = input.find("\n\n").ok_or_else(|| {
+            ParseError::new(algorithm_line, "expected an empty line after the algorithm").at_line(1)
+        })?;
+        let image = Grid::parse(&input[image_start + 2..], "`#` or `.`", |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })
+        .map_err(|mut err| {
+            err.line += input[..image_start + 2].lines().count();
+            err
+        })?;
+
+        Ok(TrenchMapInput { algorithm, image })
+    }
+
+    /// Lit pixel count after `iterations_part_1` iterations (2)
+    fn part_1(&self, input: &Self::Input) -> Self::Answer1 {
+        lit_pixel_count_after(&input.algorithm, &input.image, self.iterations_part_1)
+    }
+
+    /// Lit pixel count after `iterations_part_2` iterations (50)
+    fn part_2(&self, input: &Self::Input) -> Self::Answer2 {
+        lit_pixel_count_after(&input.algorithm, &input.image, self.iterations_part_2)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn frames_grow() {
+        let input = fs::read_to_string("test_inputs/day_20_trench_map.txt").unwrap();
+        let input = TrenchMap::PUZZLE.parse(&input).unwrap();
+        let frames: Vec<TrenchMapFrame> = frames(&input, 2).collect();
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].image, input.image);
+        assert_eq!(frames[2].image.width(), input.image.width() + 4);
+        // the example background stays dark, so all lit pixels are in the frame
+        assert!(frames[2]
+            .render()
+            .starts_with("Iteration 2, 35 lit pixels\n"));
+    }
+}
@@ -0,0 +1,317 @@
+//! Solution to an Advent of Code problem, day 21, 2021
+//! https://adventofcode.com/2021/day/21
+use crate::error::{parse_lines, parse_number_in, ParseError};
+use crate::output::Count;
+use crate::Solution;
+use std::ops::{Index, IndexMut};
+
+struct Player {
+    position: u64,
+    score: u64,
+}
+
+struct Die {
+    count: u64,
+    next: u64,
+}
+
+impl Die {
+    fn new() -> Die {
+        Die { count: 0, next: 1 }
+    }
+}
+
+impl Iterator for Die {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ret = self.next;
+        self.next = (self.next % 100) + 1;
+        self.count += 1;
+        Some(ret)
+    }
+}
+
+/// Throws where sum = 3:
+/// 111
+/// Throws where sum = 4:
+/// 112, 121, 211
+/// Throws where sum = 5:
+/// 113, 131, 311, 221, 212, 122
+/// Throws where sum = 6:
+/// 222, 123, 312, 231, 132, 213, 321
+/// Throws where sum = 7:
+/// 223, 322, 232, 331, 313, 133
+/// Throws where sum = 8:
+/// 332, 323, 233
+/// Throws where sum = 9:
+/// 333
+///
+///  Sum of three throws | Number of universes
+/// ---------------------|---------------------
+///  3                   | 1                   
+///  4                   | 3                   
+///  5                   | 6                   
+///  6                   | 7                   
+///  7                   | 6                   
+///  8                   | 3                   
+///  9                   | 1                   
+///  TOTAL               | 27                  
+const DIRAC_DICE_OUTCOMES: [(u64, u64); 7] =
+    [(3, 1), (4, 3), (5, 6), (6, 7), (7, 6), (8, 3), (9, 1)];
+
+/// Universe counts by player 1 score, player 1 position, player 2 score, player 2 position.
+/// Scores stop at the winning score, positions go from 0 to board size - 1.
+/// Counts saturate at `u64::MAX`, which marks an overflow.
+#[derive(Clone, PartialEq)]
+struct GameStateHistogram {
+    winning_score: usize,
+    board_size: usize,
+    counts: Vec<u64>,
+}
+
+impl GameStateHistogram {
+    fn new(winning_score: usize, board_size: usize) -> GameStateHistogram {
+        let scores = winning_score + 1;
+        GameStateHistogram {
+            winning_score,
+            board_size,
+            counts: vec![0; scores * board_size * scores * board_size],
+        }
+    }
+
+    fn index(&self, state: (usize, usize, usize, usize)) -> usize {
+        let (player_1_score, player_1_position, player_2_score, player_2_position) = state;
+        ((player_1_score * self.board_size + player_1_position) * (self.winning_score + 1)
+            + player_2_score)
+            * self.board_size
+            + player_2_position
+    }
+}
+
+impl Index<(usize, usize, usize, usize)> for GameStateHistogram {
+    type Output = u64;
+
+    fn index(&self, state: (usize, usize, usize, usize)) -> &u64 {
+        &self.counts[GameStateHistogram::index(self, state)]
+    }
+}
+
+impl IndexMut<(usize, usize, usize, usize)> for GameStateHistogram {
+    fn index_mut(&mut self, state: (usize, usize, usize, usize)) -> &mut u64 {
+        let i = GameStateHistogram::index(self, state);
+        &mut self.counts[i]
+    }
+}
+
+fn dirac_round(prev: &GameStateHistogram) -> GameStateHistogram {
+    let (winning_score, board_size) = (prev.winning_score, prev.board_size);
+    let mut next = GameStateHistogram::new(winning_score, board_size);
+
+    // player 1 throws the dice
+    for player_1_score in 0..=winning_score {
+        for player_1_position in 0..board_size {
+            for player_2_score in 0..=winning_score {
+                for player_2_position in 0..board_size {
+                    let state = (
+                        player_1_score,
+                        player_1_position,
+                        player_2_score,
+                        player_2_position,
+                    );
+                    if player_1_score < winning_score && player_2_score < winning_score {
+                        for (throws_sum, count) in DIRAC_DICE_OUTCOMES {
+                            let new_player_1_position =
+                                (player_1_position + throws_sum as usize) % board_size;
+                            let new_player_1_score = std::cmp::min(
+                                winning_score,
+                                player_1_score + new_player_1_position + 1,
+                            );
+                            let next_state = (
+                                new_player_1_score,
+                                new_player_1_position,
+                                player_2_score,
+                                player_2_position,
+                            );
+                            next[next_state] =
+                                next[next_state].saturating_add(prev[state].saturating_mul(count));
+                        }
+                    } else {
+                        // these games already ended
+                        next[state] = next[state].saturating_add(prev[state]);
+                    }
+                }
+            }
+        }
+    }
+
+    let prev = next;
+    let mut next = GameStateHistogram::new(winning_score, board_size);
+    // player 2 throws the dice
+    for player_1_score in 0..=winning_score {
+        for player_1_position in 0..board_size {
+            for player_2_score in 0..=winning_score {
+                for player_2_position in 0..board_size {
+                    let state = (
+                        player_1_score,
+                        player_1_position,
+                        player_2_score,
+                        player_2_position,
+                    );
+                    if player_1_score < winning_score && player_2_score < winning_score {
+                        for (throws_sum, count) in DIRAC_DICE_OUTCOMES {
+                            let new_player_2_position =
+                                (player_2_position + throws_sum as usize) % board_size;
+                            let new_player_2_score = std::cmp::min(
+                                winning_score,
+                                player_2_score + new_player_2_position + 1,
+                            );
+                            let next_state = (
+                                player_1_score,
+                                player_1_position,
+                                new_player_2_score,
+                                new_player_2_position,
+                            );
+                            next[next_state] =
+                                next[next_state].saturating_add(prev[state].saturating_mul(count));
+                        }
+                    } else {
+                        // these games already ended
+                        next[state] = next[state].saturating_add(prev[state]);
+                    }
+                }
+            }
+        }
+    }
+
+    next
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DiracDice {
+    /// Spaces on the board, numbered from 1, at most `MAX_BOARD_SIZE`
+    pub board_size: u64,
+    /// Score which wins with the deterministic die of part 1, at most `MAX_WINNING_SCORE_PART_1`
+    pub winning_score_part_1: u64,
+    /// Score which wins with the Dirac die of part 2, at most `MAX_WINNING_SCORE_PART_2`
+    pub winning_score_part_2: u64,
+}
+
+impl DiracDice {
+    /// Largest board which can be set in `config`, part 2 keeps (board size * winning score)²
+    /// universe counts
+    pub const MAX_BOARD_SIZE: u64 = 20;
+    pub const MAX_WINNING_SCORE_PART_1: u64 = 1_000_000;
+    pub const MAX_WINNING_SCORE_PART_2: u64 = 50;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: DiracDice = DiracDice {
+        board_size: 10,
+        winning_score_part_1: 1000,
+        winning_score_part_2: 21,
+    };
+}
+
+impl Solution for DiracDice {
+    const DAY: u8 = 21;
+
+    type Input = Vec<u64>;
+    type Answer1 = u64;
+    type Answer2 = Count;
+
+    /// example:
+    /// Player 1 starting position: 4
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let positions = parse_lines(input, |line| {
+            let (_, position) = line
+                .split_once(" starting position: ")
+                .ok_or_else(|| ParseError::new(line, "expected `Player N starting position: P`"))?;
+            match parse_number_in(line, position)? {
+                position if (1..=self.board_size).contains(&position) => Ok(position),
+                _ => Err(ParseError::new(
+                    line,
+                    format!("expected a position from 1 to {}", self.board_size),
+                )),
+            }
+        })?;
+        if positions.len() != 2 {
+            return Err(ParseError::new(input, "expected 2 players"));
+        }
+        Ok(positions)
+    }
+
+    /// Losing score * die roll count
+    fn part_1(&self, initial_positions: &Self::Input) -> Self::Answer1 {
+        let mut players = initial_positions
+            .iter()
+            .map(|&position| Player { position, score: 0 })
+            .collect::<Vec<Player>>();
+        let mut die = Die::new();
+        loop {
+            for player_i in 0..players.len() {
+                let player = &mut players[player_i];
+                let moves: u64 = die.by_ref().take(3).sum();
+                player.position = (player.position + moves - 1) % self.board_size + 1;
+                player.score += player.position;
+                if player.score >= self.winning_score_part_1 {
+                    return players[(player_i + 1) % 2].score * die.count;
+                }
+            }
+        }
+    }
+
+    /// Number of universes in which the player who wins more often wins
+    fn part_2(&self, initial_positions: &Self::Input) -> Self::Answer2 {
+        let winning_score = self.winning_score_part_2 as usize;
+        let board_size = self.board_size as usize;
+        let mut game_state_histogram = GameStateHistogram::new(winning_score, board_size);
+        game_state_histogram[(
+            0,
+            initial_positions[0] as usize - 1,
+            0,
+            initial_positions[1] as usize - 1,
+        )] = 1;
+
+        loop {
+            let next = dirac_round(&game_state_histogram);
+            if next == game_state_histogram {
+                // All games ended
+                let mut player_1_wins: u64 = 0;
+                let mut player_2_wins: u64 = 0;
+                for player_1_position in 0..board_size {
+                    for player_2_position in 0..board_size {
+                        for player_2_score in 0..winning_score {
+                            player_1_wins = player_1_wins.saturating_add(
+                                game_state_histogram[(
+                                    winning_score,
+                                    player_1_position,
+                                    player_2_score,
+                                    player_2_position,
+                                )],
+                            );
+                        }
+                    }
+                }
+                for player_1_position in 0..board_size {
+                    for player_2_position in 0..board_size {
+                        for player_1_score in 0..winning_score {
+                            player_2_wins = player_2_wins.saturating_add(
+                                game_state_histogram[(
+                                    player_1_score,
+                                    player_1_position,
+                                    winning_score,
+                                    player_2_position,
+                                )],
+                            );
+                        }
+                    }
+                }
+
+                let wins = std::cmp::max(player_1_wins, player_2_wins);
+                return Count((wins < u64::MAX).then_some(wins));
+            } else {
+                game_state_histogram = next;
+            }
+        }
+    }
+}
@@ -0,0 +1,253 @@
+//! Solution to an Advent of Code problem, day 22, 2021
+//! https://adventofcode.com/2021/day/22
+use crate::error::{parse_lines, parse_number_in, ParseError};
+use crate::Solution;
+use std::cmp::max;
+use std::cmp::min;
+use std::ops::Range;
+
+#[derive(Debug, Copy, Clone)]
+struct Cuboid {
+    x1: i64,
+    x2: i64,
+    y1: i64,
+    y2: i64,
+    z1: i64,
+    z2: i64,
+}
+
+#[derive(Debug)]
+pub struct Step {
+    on_off: bool,
+    cuboid: Cuboid,
+}
+
+fn clamp(x: i64, min_x: i64, max_x: i64) -> i64 {
+    min(max_x, max(min_x, x))
+}
+
+fn clamp_range(range: &Range<i64>, min_x: i64, max_x: i64) -> Range<i64> {
+    Range::<i64> {
+        start: clamp(range.start, min_x, max_x),
+        end: clamp(range.end, min_x, max_x),
+    }
+}
+
+/// example:
+/// on x=-20..26,y=-36..17,z=-47..7
+fn parse_step(line: &str) -> Result<Step, ParseError> {
+    let syntax_error = || ParseError::new(line, "expected `on|off x=a..b,y=a..b,z=a..b`");
+    let (on_off, ranges) = line.split_once(' ').ok_or_else(syntax_error)?;
+    let on_off = match on_off {
+        "on" => true,
+        "off" => false,
+        _ => return Err(syntax_error()),
+    };
+
+    let mut bounds = vec![];
+    for (range, axis) in ranges.split(',').zip(["x=", "y=", "z="]) {
+        let (from, to) = range
+            .strip_prefix(axis)
+            .and_then(|range| range.split_once(".."))
+            .ok_or_else(syntax_error)?;
+        bounds.push(parse_number_in(line, from)?);
+        bounds.push(parse_number_in(line, to)?);
+    }
+    match bounds[..] {
+        [x1, x2, y1, y2, z1, z2] if ranges.split(',').count() == 3 => Ok(Step {
+            on_off,
+            cuboid: Cuboid {
+                x1,
+                x2,
+                y1,
+                y2,
+                z1,
+                z2,
+            },
+        }),
+        _ => Err(syntax_error()),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ReactorReboot {
+    /// Part 1 only counts cubes with all coordinates in `-region..=region`, at most `MAX_REGION`
+    pub region: i64,
+}
+
+impl ReactorReboot {
+    /// Largest region which can be set in `config`, part 1 keeps a cube of (2 * region + 1)³ bools
+    pub const MAX_REGION: i64 = 200;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: ReactorReboot = ReactorReboot { region: 50 };
+}
+
+impl Solution for ReactorReboot {
+    const DAY: u8 = 22;
+
+    type Input = Vec<Step>;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, parse_step)
+    }
+
+    /// ON cubes count (within -`region`..`region`, -50..50)
+    fn part_1(&self, steps: &Self::Input) -> Self::Answer1 {
+        let region = self.region;
+        let size = (2 * region + 1) as usize;
+        let mut reactor = vec![false; size * size * size];
+        for step in steps.iter() {
+            let x_range = clamp_range(&(step.cuboid.x1..step.cuboid.x2 + 1), -region, region + 1);
+            let y_range = clamp_range(&(step.cuboid.y1..step.cuboid.y2 + 1), -region, region + 1);
+            let z_range = clamp_range(&(step.cuboid.z1..step.cuboid.z2 + 1), -region, region + 1);
+            for x in x_range.clone() {
+                for y in y_range.clone() {
+                    for z in z_range.clone() {
+                        let i = (x + region) as usize;
+                        let j = (y + region) as usize;
+                        let k = (z + region) as usize;
+                        reactor[(i * size + j) * size + k] = step.on_off;
+                    }
+                }
+            }
+        }
+
+        reactor.iter().filter(|&&on| on).count() as u64
+    }
+
+    /// ON cubes count
+    fn part_2(&self, steps: &Self::Input) -> Self::Answer2 {
+        let mut x_splits: Vec<i64> = vec![];
+        let mut y_splits: Vec<i64> = vec![];
+        let mut z_splits: Vec<i64> = vec![];
+        for step in steps.iter() {
+            x_splits.push(step.cuboid.x1 - 1);
+            x_splits.push(step.cuboid.x1);
+            x_splits.push(step.cuboid.x2);
+            x_splits.push(step.cuboid.x2 + 1);
+            y_splits.push(step.cuboid.y1 - 1);
+            y_splits.push(step.cuboid.y1);
+            y_splits.push(step.cuboid.y2);
+            y_splits.push(step.cuboid.y2 + 1);
+            z_splits.push(step.cuboid.z1 - 1);
+            z_splits.push(step.cuboid.z1);
+            z_splits.push(step.cuboid.z2);
+            z_splits.push(step.cuboid.z2 + 1);
+        }
+        x_splits.sort_unstable();
+        y_splits.sort_unstable();
+        z_splits.sort_unstable();
+        x_splits.dedup();
+        y_splits.dedup();
+        z_splits.dedup();
+
+        let mut landscape = vec![vec![vec![false; x_splits.len()]; y_splits.len()]; z_splits.len()];
+        for step in steps.iter() {
+            let i1 = x_splits.binary_search(&step.cuboid.x1).unwrap();
+            let i2 = x_splits.binary_search(&step.cuboid.x2).unwrap();
+            let j1 = y_splits.binary_search(&step.cuboid.y1).unwrap();
+            let j2 = y_splits.binary_search(&step.cuboid.y2).unwrap();
+            let k1 = z_splits.binary_search(&step.cuboid.z1).unwrap();
+            let k2 = z_splits.binary_search(&step.cuboid.z2).unwrap();
+            #[allow(clippy::needless_range_loop)]
+            for k in k1..(k2 + 1) {
+                for j in j1..(j2 + 1) {
+                    for i in i1..(i2 + 1) {
+                        landscape[k][j][i] = step.on_off;
+                    }
+                }
+            }
+        }
+
+        let mut total_count: u64 = 0;
+        // no steps leave no splits
+        for k in 0..z_splits.len().saturating_sub(1) {
+            let dz = (z_splits[k + 1] - z_splits[k]) as u64;
+            for j in 0..y_splits.len() - 1 {
+                let dy = (y_splits[j + 1] - y_splits[j]) as u64;
+                for i in 0..x_splits.len() - 1 {
+                    let dx = (x_splits[i + 1] - x_splits[i]) as u64;
+                    if landscape[k][j][i] {
+                        total_count += dx * dy * dz;
+                    }
+                }
+            }
+        }
+
+        total_count
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+    use std::collections::HashSet;
+
+    /// Turns single cubes on and off
+    fn brute_force_on_count(steps: &[Step]) -> u64 {
+        let mut on = HashSet::new();
+        for step in steps {
+            let cuboid = step.cuboid;
+            for x in cuboid.x1..=cuboid.x2 {
+                for y in cuboid.y1..=cuboid.y2 {
+                    for z in cuboid.z1..=cuboid.z2 {
+                        if step.on_off {
+                            on.insert((x, y, z));
+                        } else {
+                            on.remove(&(x, y, z));
+                        }
+                    }
+                }
+            }
+        }
+        on.len() as u64
+    }
+
+    #[test]
+    fn landscape_matches_brute_force() {
+        let mut rng = Rng::new(22);
+        for _ in 0..200 {
+            let mut steps = vec![];
+            for _ in 0..1 + rng.below(8) {
+                let [(x1, x2), (y1, y2), (z1, z2)] = [0, 1, 2].map(|_| {
+                    let start = rng.range(-10..=10);
+                    (start, start + rng.range(0..=8))
+                });
+                steps.push(Step {
+                    on_off: rng.chance(0.6),
+                    cuboid: Cuboid {
+                        x1,
+                        x2,
+                        y1,
+                        y2,
+                        z1,
+                        z2,
+                    },
+                });
+            }
+
+            let expected = brute_force_on_count(&steps);
+            assert_eq!(
+                ReactorReboot::PUZZLE.part_1(&steps),
+                expected,
+                "{:?}",
+                steps
+            );
+            assert_eq!(
+                ReactorReboot::PUZZLE.part_2(&steps),
+                expected,
+                "{:?}",
+                steps
+            );
+        }
+
+        // no steps leave all cubes off
+        let steps = ReactorReboot::PUZZLE.parse("").unwrap();
+        assert_eq!(ReactorReboot::PUZZLE.part_1(&steps), 0);
+        assert_eq!(ReactorReboot::PUZZLE.part_2(&steps), 0);
+    }
+}
@@ -0,0 +1,509 @@
+//! Solution to an Advent of Code problem, day 23, 2021
+//! https://adventofcode.com/2021/day/23
+#![allow(clippy::needless_range_loop)]
+use crate::error::ParseError;
+use crate::visualize::Frame;
+use crate::Solution;
+use std::fmt::Display;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Amphipod {
+    A,
+    B,
+    C,
+    D,
+}
+
+impl TryFrom<char> for Amphipod {
+    type Error = ();
+    fn try_from(chr: char) -> Result<Self, Self::Error> {
+        match chr {
+            'A' => Ok(Amphipod::A),
+            'B' => Ok(Amphipod::B),
+            'C' => Ok(Amphipod::C),
+            'D' => Ok(Amphipod::D),
+            _ => Err(()),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BurrowOccupancyV2<const N: usize> {
+    hallway: [Option<Amphipod>; 11],
+    rooms: [[Option<Amphipod>; N]; 4],
+}
+
+fn maybe_amphipod_as_char(maybe_amphipod: Option<Amphipod>) -> char {
+    match maybe_amphipod {
+        Some(Amphipod::A) => 'A',
+        Some(Amphipod::B) => 'B',
+        Some(Amphipod::C) => 'C',
+        Some(Amphipod::D) => 'D',
+        None => '.',
+    }
+}
+
+impl<const N: usize> Display for BurrowOccupancyV2<N> {
+    /// Output example:
+    /// #############
+    /// #...........#
+    /// ###B#C#B#D###
+    ///   #A#D#C#A#
+    ///   #########
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "#############")?;
+        let hallway: String = self
+            .hallway
+            .iter()
+            .copied()
+            .map(maybe_amphipod_as_char)
+            .collect();
+        writeln!(f, "#{}#", hallway)?;
+        for i in 0..N {
+            let room_row: Vec<String> = self
+                .rooms
+                .iter()
+                .map(|room| maybe_amphipod_as_char(room[i]).to_string())
+                .collect();
+            if i == 0 {
+                writeln!(f, "###{}###", room_row.join("#"))?;
+            } else {
+                writeln!(f, "  #{}#", room_row.join("#"))?;
+            }
+        }
+        write!(f, "  #########")
+    }
+}
+
+fn cost_per_step(amphipod: Amphipod) -> usize {
+    match amphipod {
+        Amphipod::A => 1,
+        Amphipod::B => 10,
+        Amphipod::C => 100,
+        Amphipod::D => 1000,
+    }
+}
+
+fn room_position(room_index: usize) -> usize {
+    match room_index {
+        0 => 2,
+        1 => 4,
+        2 => 6,
+        3 => 8,
+        _ => panic!("There are only 4 rooms!"),
+    }
+}
+
+fn room_owner(room_index: usize) -> Amphipod {
+    match room_index {
+        0 => Amphipod::A,
+        1 => Amphipod::B,
+        2 => Amphipod::C,
+        3 => Amphipod::D,
+        _ => panic!("There are only 4 rooms!"),
+    }
+}
+
+fn movements_from_room_to_hallway_v2<const N: usize>(
+    occupancy: &BurrowOccupancyV2<N>,
+    room_index: usize,
+) -> [Option<usize>; 11] {
+    let mut result = [None; 11];
+    let position = room_position(room_index);
+    if occupancy.hallway[position].is_some() {
+        return result;
+    }
+
+    for i in (0..position).rev() {
+        if occupancy.hallway[i].is_some() {
+            break;
+        }
+        if i != room_position(0)
+            && i != room_position(1)
+            && i != room_position(2)
+            && i != room_position(3)
+        {
+            result[i] = Some(position - i);
+        }
+    }
+    for i in (position + 1)..11 {
+        if occupancy.hallway[i].is_some() {
+            break;
+        }
+        if i != room_position(0)
+            && i != room_position(1)
+            && i != room_position(2)
+            && i != room_position(3)
+        {
+            result[i] = Some(i - position);
+        }
+    }
+    result
+}
+
+fn movements_from_hallway_to_room_v2<const N: usize>(
+    occupancy: &BurrowOccupancyV2<N>,
+    room_index: usize,
+) -> [Option<usize>; 11] {
+    let owner = room_owner(room_index);
+    let position = room_position(room_index);
+
+    let mut result = [None; 11];
+    for i in (0..position + 1).rev() {
+        if occupancy.hallway[i] == Some(owner) {
+            result[i] = Some(position - i);
+            break;
+        } else if occupancy.hallway[i].is_some() {
+            break;
+        }
+    }
+    for i in position..11 {
+        if occupancy.hallway[i] == Some(owner) {
+            result[i] = Some(i - position);
+            break;
+        } else if occupancy.hallway[i].is_some() {
+            break;
+        }
+    }
+
+    result
+}
+
+fn generate_possible_next_configurations_v2<const N: usize>(
+    occupancy: &BurrowOccupancyV2<N>,
+) -> Vec<(usize, BurrowOccupancyV2<N>)> {
+    let mut configurations = vec![];
+
+    let mut tmp;
+
+    let mut only_owners_inside = [true; 4];
+    for room_index in 0..4 {
+        let room_owner = room_owner(room_index);
+        for i in 0..N {
+            if occupancy.rooms[room_index][i] != Some(room_owner)
+                && occupancy.rooms[room_index][i].is_some()
+            {
+                only_owners_inside[room_index] = false;
+                break;
+            }
+        }
+    }
+    // into a home room.
+    // Short-circuiting when going into a home room is the
+    // most significant optimization when exploring options in this game
+    for room_index in 0..4 {
+        if only_owners_inside[room_index] {
+            let sources = movements_from_hallway_to_room_v2(occupancy, room_index);
+            for j in (0..N).rev() {
+                if occupancy.rooms[room_index][j].is_none() {
+                    for i in 0..11 {
+                        if let Some(cost_along_hallway) = sources[i] {
+                            if Some(room_owner(room_index)) == occupancy.hallway[i] {
+                                tmp = *occupancy;
+                                tmp.rooms[room_index][j] = tmp.hallway[i].take();
+                                return vec![(
+                                    cost_per_step(room_owner(room_index))
+                                        * (cost_along_hallway + j + 1),
+                                    tmp,
+                                )];
+                            }
+                        }
+                    }
+
+                    break;
+                }
+            }
+        }
+    }
+
+    // out of each room
+    for room_index in 0..4 {
+        if !only_owners_inside[room_index] {
+            let destinations = movements_from_room_to_hallway_v2(occupancy, room_index);
+            for j in 0..N {
+                if let Some(moved) = occupancy.rooms[room_index][j] {
+                    let cps = cost_per_step(moved);
+                    for i in 0..11 {
+                        if let Some(cost_along_hallway) = destinations[i] {
+                            tmp = *occupancy;
+                            tmp.hallway[i] = tmp.rooms[room_index][j].take();
+                            configurations.push((cps * (cost_along_hallway + j + 1), tmp));
+                        }
+                    }
+                    break;
+                }
+            }
+        }
+    }
+
+    configurations
+}
+
+/// Occupant of a cell of the diagram, `.` if it is empty
+fn parse_cell(chr: char) -> Option<Option<Amphipod>> {
+    match chr {
+        '.' => Some(None),
+        chr => Amphipod::try_from(chr).ok().map(Some),
+    }
+}
+
+/// Occupants of a line of the diagram, which must match `pattern` but for the cells marked `x`
+fn parse_cells(line: &str, pattern: &str) -> Option<Vec<Option<Amphipod>>> {
+    let line = line.trim_end();
+    if line.chars().count() != pattern.len() {
+        return None;
+    }
+    let mut cells = vec![];
+    for (chr, expected) in line.chars().zip(pattern.chars()) {
+        if expected == 'x' {
+            cells.push(parse_cell(chr)?);
+        } else if chr != expected {
+            return None;
+        }
+    }
+    Some(cells)
+}
+
+/// Parse input
+/// Input example:
+/// #############
+/// #...........#
+/// ###B#C#B#D###
+///   #A#D#C#A#
+///   #########
+fn parse_input_p1(input: &str) -> Option<BurrowOccupancyV2<2>> {
+    let mut lines = input.lines();
+    parse_cells(lines.next()?, "#############")?;
+    let hallway = parse_cells(lines.next()?, "#xxxxxxxxxxx#")?;
+    let rooms_front = parse_cells(lines.next()?, "###x#x#x#x###")?;
+    let rooms_back = parse_cells(lines.next()?, "  #x#x#x#x#")?;
+    parse_cells(lines.next()?, "  #########")?;
+    if lines.any(|line| !line.trim().is_empty()) {
+        return None;
+    }
+
+    Some(BurrowOccupancyV2 {
+        hallway: hallway.try_into().ok()?,
+        rooms: [0, 1, 2, 3].map(|room| [rooms_front[room], rooms_back[room]]),
+    })
+}
+
+/// Burrow of part 2, with the folded part of the diagram between the two lines of rooms:
+///   #D#C#B#A#
+///   #D#B#A#C#
+fn unfold(folded: &BurrowOccupancyV2<2>) -> BurrowOccupancyV2<4> {
+    let unfolded_rooms = [
+        [Amphipod::D, Amphipod::D],
+        [Amphipod::C, Amphipod::B],
+        [Amphipod::B, Amphipod::A],
+        [Amphipod::A, Amphipod::C],
+    ];
+    BurrowOccupancyV2 {
+        hallway: folded.hallway,
+        rooms: [0, 1, 2, 3].map(|room| {
+            let [front, back] = folded.rooms[room];
+            let [first, second] = unfolded_rooms[room];
+            [front, Some(first), Some(second), back]
+        }),
+    }
+}
+
+fn is_done_v2<const N: usize>(occupancy: &BurrowOccupancyV2<N>) -> bool {
+    for room_index in 0..4 {
+        let room_owner = room_owner(room_index);
+        for i in 0..N {
+            if occupancy.rooms[room_index][i] != Some(room_owner) {
+                return false;
+            }
+        }
+    }
+    true
+}
+
+/// Min cost and the configurations on the way there, starting with `input`
+fn cheapest_moves<const N: usize>(
+    input: BurrowOccupancyV2<N>,
+) -> (usize, Vec<(usize, BurrowOccupancyV2<N>)>) {
+    // depth first, `path` holds the configurations leading to the current one
+    let mut options = vec![(0, 0, input)];
+    let mut path = vec![];
+    let mut current_min_cost = usize::MAX;
+    let mut cheapest_path = vec![];
+    while let Some((depth, cost, occupancy)) = options.pop() {
+        path.truncate(depth);
+        path.push((cost, occupancy));
+        if cost >= current_min_cost { //skip
+        } else if is_done_v2(&occupancy) {
+            current_min_cost = cost;
+            cheapest_path = path.clone();
+        } else {
+            for (cost_next, next) in generate_possible_next_configurations_v2(&occupancy) {
+                options.push((depth + 1, cost + cost_next, next));
+            }
+        }
+    }
+    (current_min_cost, cheapest_path)
+}
+
+fn solve<const N: usize>(input: BurrowOccupancyV2<N>) -> usize {
+    cheapest_moves(input).0
+}
+
+/// Burrow after a move of the cheapest solution
+pub struct BurrowFrame<const N: usize> {
+    move_index: usize,
+    cost: usize,
+    occupancy: BurrowOccupancyV2<N>,
+}
+
+impl<const N: usize> Frame for BurrowFrame<N> {
+    fn render(&self) -> String {
+        format!(
+            "Move {}, cost {}\n{}",
+            self.move_index, self.cost, self.occupancy
+        )
+    }
+}
+
+fn frames<const N: usize>(input: BurrowOccupancyV2<N>) -> impl Iterator<Item = BurrowFrame<N>> {
+    cheapest_moves(input)
+        .1
+        .into_iter()
+        .enumerate()
+        .map(|(move_index, (cost, occupancy))| BurrowFrame {
+            move_index,
+            cost,
+            occupancy,
+        })
+}
+
+/// Moves of the cheapest solution of part 1, starting with the initial burrow
+pub fn folded_frames(burrows: &Burrows) -> impl Iterator<Item = BurrowFrame<2>> {
+    frames(burrows.folded)
+}
+
+/// Moves of the cheapest solution of part 2, starting with the initial burrow
+pub fn unfolded_frames(burrows: &Burrows) -> impl Iterator<Item = BurrowFrame<4>> {
+    frames(burrows.unfolded)
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Burrows {
+    folded: BurrowOccupancyV2<2>,
+    unfolded: BurrowOccupancyV2<4>,
+}
+
+impl Display for Burrows {
+    /// The diagram of the folded burrow, as in the input
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{}", self.folded)
+    }
+}
+
+pub struct Amphipods;
+
+impl Solution for Amphipods {
+    const DAY: u8 = 23;
+
+    type Input = Burrows;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let syntax_error = || ParseError::new(input, "expected a diagram of the burrow");
+        let folded = parse_input_p1(input).ok_or_else(syntax_error)?;
+        if (0..4).any(|room| folded.hallway[room_position(room)].is_some()) {
+            return Err(ParseError::new(
+                input,
+                "amphipods never stop in front of a room",
+            ));
+        }
+
+        for amphipod in [Amphipod::A, Amphipod::B, Amphipod::C, Amphipod::D] {
+            let count = folded
+                .rooms
+                .iter()
+                .flatten()
+                .chain(folded.hallway.iter())
+                .filter(|&&occupant| occupant == Some(amphipod))
+                .count();
+            if count != 2 {
+                return Err(ParseError::new(
+                    input,
+                    format!(
+                        "expected 2 amphipods of type {:?}, found {}",
+                        amphipod, count
+                    ),
+                ));
+            }
+        }
+
+        Ok(Burrows {
+            folded,
+            unfolded: unfold(&folded),
+        })
+    }
+
+    /// Min cost
+    fn part_1(&self, burrows: &Self::Input) -> Self::Answer1 {
+        solve(burrows.folded)
+    }
+
+    /// Min cost, with the folded part of the diagram unfolded
+    fn part_2(&self, burrows: &Self::Input) -> Self::Answer2 {
+        solve(burrows.unfolded)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn parse() {
+        let input = fs::read_to_string("test_inputs/day_23_amphipod.txt").unwrap();
+        let burrows = Amphipods.parse(&input).unwrap();
+        assert_eq!(burrows.to_string(), format!("{}\n", input.trim_end()));
+        assert_eq!(
+            burrows.unfolded.to_string(),
+            "#############\n\
+             #...........#\n\
+             ###B#C#B#D###\n  \
+               #D#C#B#A#\n  \
+               #D#B#A#C#\n  \
+               #A#D#C#A#\n  \
+               #########"
+        );
+
+        let wall = input.replacen("###B", "#.#B", 1);
+        assert!(Amphipods.parse(&wall).is_err());
+        let unknown_cell = input.replacen("#...", "#x..", 1);
+        assert!(Amphipods.parse(&unknown_cell).is_err());
+        let in_front_of_room = input
+            .replacen("#...", "#..A", 1)
+            .replacen("#A#D", "#.#D", 1);
+        assert!(Amphipods.parse(&in_front_of_room).is_err());
+        let in_hallway = input
+            .replacen("#...", "#A..", 1)
+            .replacen("#A#D", "#.#D", 1);
+        assert!(Amphipods.parse(&in_hallway).is_ok());
+        assert!(Amphipods.parse(&(input.clone() + "#")).is_err());
+    }
+
+    #[test]
+    fn frames_of_cheapest_solution() {
+        let input = fs::read_to_string("test_inputs/day_23_amphipod.txt").unwrap();
+        let burrows = Amphipods.parse(&input).unwrap();
+        let frames: Vec<BurrowFrame<2>> = folded_frames(&burrows).collect();
+
+        assert_eq!(
+            frames[0].render(),
+            format!("Move 0, cost 0\n{}", input.trim_end())
+        );
+        let last = frames.last().unwrap();
+        assert_eq!(last.cost, 12521);
+        assert!(is_done_v2(&last.occupancy));
+        assert!(frames.windows(2).all(|pair| pair[0].cost < pair[1].cost));
+    }
+}
@@ -0,0 +1,500 @@
+//! Solution to an Advent of Code problem, day 24, 2021
+//! https://adventofcode.com/2021/day/24
+use crate::error::{parse_lines, parse_number_in, ParseError};
+use crate::Solution;
+use std::collections::HashSet;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+fn parse_register(line: &str, s: &str) -> Result<Register, ParseError> {
+    match s {
+        "w" => Ok(Register::W),
+        "x" => Ok(Register::X),
+        "y" => Ok(Register::Y),
+        "z" => Ok(Register::Z),
+        _ => {
+            let err = ParseError::new(line, format!("expected a register, found `{}`", s));
+            Err(match crate::error::column_of(line, s) {
+                Some(column) => err.at_column(column),
+                None => err,
+            })
+        }
+    }
+}
+
+fn parse_operand(line: &str, s: &str) -> Result<Operand, ParseError> {
+    match s {
+        "w" | "x" | "y" | "z" => Ok(Operand::Register(parse_register(line, s)?)),
+        _ => Ok(Operand::Number(parse_number_in(line, s)?)),
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    /// examples:
+    /// inp w
+    /// add x -13
+    /// eql x w
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words = s.split(' ').collect::<Vec<&str>>();
+        match words[..] {
+            ["inp", a] => Ok(Instruction::Inp(parse_register(s, a)?)),
+            [operation, a, b] => {
+                let a = parse_register(s, a)?;
+                let b = parse_operand(s, b)?;
+                match operation {
+                    "add" => Ok(Instruction::Add(a, b)),
+                    "mul" => Ok(Instruction::Mul(a, b)),
+                    "div" => Ok(Instruction::Div(a, b)),
+                    "mod" => Ok(Instruction::Mod(a, b)),
+                    "eql" => Ok(Instruction::Eql(a, b)),
+                    _ => Err(
+                        ParseError::new(s, format!("unknown instruction `{}`", operation))
+                            .at_column(1),
+                    ),
+                }
+            }
+            _ => Err(ParseError::new(
+                s,
+                "expected `inp a` or `add|mul|div|mod|eql a b`",
+            )),
+        }
+    }
+}
+
+/// Values of registers w, x, y and z
+type Registers = [i64; 4];
+
+/// Reasons for the ALU to stop before the end of the program
+#[derive(Debug, PartialEq)]
+pub enum AluError {
+    MissingInput,
+    DivisionByZero,
+    InvalidModulo,
+}
+
+fn value(registers: &Registers, operand: Operand) -> i64 {
+    match operand {
+        Operand::Register(register) => registers[register as usize],
+        Operand::Number(n) => n,
+    }
+}
+
+/// Runs one instruction. `input` is only used by `inp`.
+fn execute<I: Iterator<Item = i64>>(
+    registers: &mut Registers,
+    instruction: Instruction,
+    input: &mut I,
+) -> Result<(), AluError> {
+    match instruction {
+        Instruction::Inp(a) => {
+            registers[a as usize] = input.next().ok_or(AluError::MissingInput)?;
+        }
+        // arbitrary programs may overflow, the ALU is assumed to wrap around
+        Instruction::Add(a, b) => {
+            registers[a as usize] = registers[a as usize].wrapping_add(value(registers, b))
+        }
+        Instruction::Mul(a, b) => {
+            registers[a as usize] = registers[a as usize].wrapping_mul(value(registers, b))
+        }
+        Instruction::Div(a, b) => {
+            let b = value(registers, b);
+            if b == 0 {
+                return Err(AluError::DivisionByZero);
+            }
+            // truncates toward zero, like the ALU
+            registers[a as usize] = registers[a as usize].wrapping_div(b);
+        }
+        Instruction::Mod(a, b) => {
+            let b = value(registers, b);
+            if registers[a as usize] < 0 || b <= 0 {
+                return Err(AluError::InvalidModulo);
+            }
+            registers[a as usize] %= b;
+        }
+        Instruction::Eql(a, b) => {
+            registers[a as usize] = (registers[a as usize] == value(registers, b)) as i64;
+        }
+    }
+    Ok(())
+}
+
+/// Runs a program from the initial state (all registers 0) and returns the final registers
+pub fn run<I: IntoIterator<Item = i64>>(
+    program: &[Instruction],
+    input: I,
+) -> Result<Registers, AluError> {
+    let mut registers = [0; 4];
+    let mut input = input.into_iter();
+    for &instruction in program {
+        execute(&mut registers, instruction, &mut input)?;
+    }
+    Ok(registers)
+}
+
+fn digits(model_number: u64) -> Vec<i64> {
+    model_number
+        .to_string()
+        .chars()
+        .map(|c| c.to_digit(10).unwrap() as i64)
+        .collect()
+}
+
+/// MONAD accepts a model number, if z is 0 at the end
+fn is_accepted(program: &[Instruction], model_number: u64) -> bool {
+    let digits = digits(model_number);
+    !digits.contains(&0) && matches!(run(program, digits), Ok([_, _, _, 0]))
+}
+
+/// Parameters of one block of MONAD, the block processes one digit.
+/// Every block computes:
+/// ```text
+/// x = (z % 26 + add_x) != w
+/// z = z / div_z
+/// if x { z = z * 26 + w + add_y }
+/// ```
+/// So z is a stack of base 26 digits: blocks with `div_z` 1 push `w + add_y`,
+/// blocks with `div_z` 26 pop, and push again unless `w` equals the popped value + `add_x`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Block {
+    div_z: i64,
+    add_x: i64,
+    add_y: i64,
+}
+
+/// Instructions of a block, `_` marks the parameters: div_z, add_x and add_y
+const BLOCK_TEMPLATE: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z _", "add x _", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y _",
+    "mul y x", "add z y",
+];
+
+/// Extracts parameters of the blocks, if the program has the shape of MONAD
+fn analyze(program: &[Instruction]) -> Option<Vec<Block>> {
+    if program.is_empty() || !program.len().is_multiple_of(BLOCK_TEMPLATE.len()) {
+        return None;
+    }
+
+    program
+        .chunks(BLOCK_TEMPLATE.len())
+        .map(|block| {
+            let mut parameters = vec![];
+            for (&instruction, template) in block.iter().zip(BLOCK_TEMPLATE) {
+                let expected = match template.strip_suffix(" _") {
+                    Some(operation) => {
+                        let n = match instruction {
+                            Instruction::Add(_, Operand::Number(n))
+                            | Instruction::Div(_, Operand::Number(n)) => n,
+                            _ => return None,
+                        };
+                        parameters.push(n);
+                        format!("{} {}", operation, n)
+                    }
+                    None => template.to_string(),
+                };
+                if expected.parse::<Instruction>().ok()? != instruction {
+                    return None;
+                }
+            }
+            Some(Block {
+                div_z: parameters[0],
+                add_x: parameters[1],
+                add_y: parameters[2],
+            })
+        })
+        .collect()
+}
+
+/// Which digits are preferred, when choosing a model number
+#[derive(Clone, Copy)]
+enum Preference {
+    Largest,
+    Smallest,
+}
+
+/// Solves the stack constraints of MONAD: each pushing block is paired with the popping block,
+/// which pops its value. The popping digit must equal the pushing digit + `add_y` + `add_x`.
+/// Returns `None`, if the blocks don't form such pairs, e.g. a pushing block can't be popped.
+fn solve_blocks(blocks: &[Block], preference: Preference) -> Option<u64> {
+    let mut digits = vec![0; blocks.len()];
+    let mut stack = vec![];
+    for (j, block) in blocks.iter().enumerate() {
+        match block.div_z {
+            // the x check can't pass with add_x >= 10, so the block always pushes
+            1 if block.add_x >= 10 => stack.push(j),
+            26 => {
+                let i = stack.pop()?;
+                let difference = blocks[i].add_y + block.add_x;
+                let digit_i = match preference {
+                    Preference::Largest => std::cmp::min(9, 9 - difference),
+                    Preference::Smallest => std::cmp::max(1, 1 - difference),
+                };
+                let digit_j = digit_i + difference;
+                if !(1..=9).contains(&digit_i) || !(1..=9).contains(&digit_j) {
+                    return None;
+                }
+                digits[i] = digit_i;
+                digits[j] = digit_j;
+            }
+            _ => return None,
+        }
+    }
+
+    if stack.is_empty() {
+        Some(
+            digits
+                .iter()
+                .fold(0, |number, &digit| number * 10 + digit as u64),
+        )
+    } else {
+        None
+    }
+}
+
+/// Depth first search over the digits, for programs which don't have the shape of MONAD.
+/// States (block index and registers) which lead to no accepted number are remembered.
+fn search(program: &[Instruction], preference: Preference) -> Option<u64> {
+    if !matches!(program.first(), Some(Instruction::Inp(_))) {
+        return None;
+    }
+
+    // each block starts with `inp`
+    let mut blocks: Vec<&[Instruction]> = vec![];
+    let mut block_start = 0;
+    for (i, instruction) in program.iter().enumerate().skip(1) {
+        if matches!(instruction, Instruction::Inp(_)) {
+            blocks.push(&program[block_start..i]);
+            block_start = i;
+        }
+    }
+    blocks.push(&program[block_start..]);
+
+    let digit_order: Vec<i64> = match preference {
+        Preference::Largest => (1..=9).rev().collect(),
+        Preference::Smallest => (1..=9).collect(),
+    };
+
+    fn search_from(
+        blocks: &[&[Instruction]],
+        registers: Registers,
+        digit_order: &[i64],
+        dead_ends: &mut HashSet<(usize, Registers)>,
+    ) -> Option<Vec<i64>> {
+        if blocks.is_empty() {
+            return if registers[Register::Z as usize] == 0 {
+                Some(vec![])
+            } else {
+                None
+            };
+        }
+        if dead_ends.contains(&(blocks.len(), registers)) {
+            return None;
+        }
+
+        for &digit in digit_order {
+            let mut next = registers;
+            let mut input = std::iter::once(digit);
+            if blocks[0]
+                .iter()
+                .try_for_each(|&instruction| execute(&mut next, instruction, &mut input))
+                .is_err()
+            {
+                continue;
+            }
+            if let Some(mut digits) = search_from(&blocks[1..], next, digit_order, dead_ends) {
+                digits.insert(0, digit);
+                return Some(digits);
+            }
+        }
+
+        dead_ends.insert((blocks.len(), registers));
+        None
+    }
+
+    search_from(&blocks, [0; 4], &digit_order, &mut HashSet::new()).map(|digits| {
+        digits
+            .iter()
+            .fold(0, |number, &digit| number * 10 + digit as u64)
+    })
+}
+
+/// Model number accepted by MONAD, found by analyzing its blocks if possible.
+/// `None` if MONAD accepts no model number.
+fn find_model_number(program: &[Instruction], preference: Preference) -> Option<u64> {
+    analyze(program)
+        .and_then(|blocks| solve_blocks(&blocks, preference))
+        .filter(|&model_number| is_accepted(program, model_number))
+        .or_else(|| search(program, preference))
+}
+
+/// Answer of a part, `none` if MONAD accepts no model number
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ModelNumber(pub Option<u64>);
+
+impl fmt::Display for ModelNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.0 {
+            Some(model_number) => write!(f, "{}", model_number),
+            None => write!(f, "none"),
+        }
+    }
+}
+
+pub struct ArithmeticLogicUnit;
+
+impl Solution for ArithmeticLogicUnit {
+    const DAY: u8 = 24;
+
+    type Input = Vec<Instruction>;
+    type Answer1 = ModelNumber;
+    type Answer2 = ModelNumber;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Instruction::from_str)
+    }
+
+    /// Largest model number accepted by MONAD
+    fn part_1(&self, program: &Self::Input) -> Self::Answer1 {
+        ModelNumber(find_model_number(program, Preference::Largest))
+    }
+
+    /// Smallest model number accepted by MONAD
+    fn part_2(&self, program: &Self::Input) -> Self::Answer2 {
+        ModelNumber(find_model_number(program, Preference::Smallest))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn parse_program(s: &str) -> Vec<Instruction> {
+        ArithmeticLogicUnit.parse(s).unwrap()
+    }
+
+    #[test]
+    fn parse() {
+        assert_eq!(
+            "add x -13".parse::<Instruction>().unwrap(),
+            Instruction::Add(Register::X, Operand::Number(-13))
+        );
+        assert_eq!(
+            "eql x w".parse::<Instruction>().unwrap(),
+            Instruction::Eql(Register::X, Operand::Register(Register::W))
+        );
+        assert_eq!("inp q".parse::<Instruction>().unwrap_err().column, Some(5));
+        assert!("sub x 1".parse::<Instruction>().is_err());
+        assert!("add x".parse::<Instruction>().is_err());
+    }
+
+    #[test]
+    fn run_examples() {
+        let negate = parse_program("inp x\nmul x -1");
+        assert_eq!(run(&negate, [7]).unwrap()[Register::X as usize], -7);
+
+        let three_times = parse_program("inp z\ninp x\nmul z 3\neql z x");
+        assert_eq!(run(&three_times, [2, 6]).unwrap()[Register::Z as usize], 1);
+        assert_eq!(run(&three_times, [2, 7]).unwrap()[Register::Z as usize], 0);
+
+        let binary = parse_program(
+            "inp w\nadd z w\nmod z 2\ndiv w 2\nadd y w\nmod y 2\ndiv w 2\nadd x w\nmod x 2\ndiv w 2\nmod w 2",
+        );
+        assert_eq!(run(&binary, [11]).unwrap(), [1, 0, 1, 1]);
+
+        assert_eq!(run(&negate, []), Err(AluError::MissingInput));
+        assert_eq!(
+            run(&parse_program("inp x\ndiv x y"), [1]),
+            Err(AluError::DivisionByZero)
+        );
+        assert_eq!(
+            run(&parse_program("inp x\nmod x -2"), [1]),
+            Err(AluError::InvalidModulo)
+        );
+    }
+
+    /// MONAD with the given parameters (div_z, add_x, add_y) of each block
+    fn monad(blocks: &[(i64, i64, i64)]) -> Vec<Instruction> {
+        let mut program = String::new();
+        for &(div_z, add_x, add_y) in blocks {
+            let mut parameters = [div_z, add_x, add_y].into_iter();
+            for template in BLOCK_TEMPLATE {
+                match template.strip_suffix('_') {
+                    Some(operation) => {
+                        program.push_str(&format!("{}{}\n", operation, parameters.next().unwrap()))
+                    }
+                    None => program.push_str(&format!("{}\n", template)),
+                }
+            }
+        }
+        parse_program(&program)
+    }
+
+    #[test]
+    fn analysis_matches_search() {
+        let example = parse_program(
+            &fs::read_to_string("test_inputs/day_24_arithmetic_logic_unit.txt").unwrap(),
+        );
+        assert_eq!(example, monad(&[(1, 12, 4), (26, -6, 1)]));
+
+        let program = monad(&[
+            (1, 12, 4),
+            (1, 11, 7),
+            (26, -3, 1),
+            (1, 14, 2),
+            (26, -10, 5),
+            (26, -6, 9),
+        ]);
+        let blocks = analyze(&program).unwrap();
+        assert_eq!(blocks.len(), 6);
+        for preference in [Preference::Largest, Preference::Smallest] {
+            let analyzed = solve_blocks(&blocks, preference).unwrap();
+            assert!(is_accepted(&program, analyzed));
+            assert_eq!(Some(analyzed), search(&program, preference));
+        }
+        assert_eq!(solve_blocks(&blocks, Preference::Largest), Some(959_917));
+    }
+
+    #[test]
+    fn search_any_program() {
+        // accepts two digits, where the second is the first + 3
+        let program = parse_program("inp w\ninp z\nadd w 3\nmul w -1\nadd z w");
+        assert_eq!(analyze(&program), None);
+        assert_eq!(find_model_number(&program, Preference::Largest), Some(69));
+        assert_eq!(find_model_number(&program, Preference::Smallest), Some(14));
+
+        // z is never 0
+        let program = parse_program("inp w\nadd z 1");
+        assert_eq!(find_model_number(&program, Preference::Largest), None);
+        assert_eq!(ArithmeticLogicUnit.part_1(&program).to_string(), "none");
+        // overflows wrap around
+        let program =
+            parse_program("inp z\nmul z 9223372036854775807\nmul z 9223372036854775807\ndiv z -1");
+        run(&program, [9]).unwrap();
+    }
+}
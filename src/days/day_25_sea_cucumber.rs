@@ -0,0 +1,156 @@
+//! Solution to an Advent of Code problem, day 25, 2021
+//! https://adventofcode.com/2021/day/25
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::image::{Image, ToImage, DARK};
+use crate::visualize::Frame;
+use crate::Solution;
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum SeaCucumber {
+    Eastbound,
+    Southbound,
+}
+
+impl TryFrom<char> for SeaCucumber {
+    type Error = ();
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            '>' => Ok(SeaCucumber::Eastbound),
+            'v' => Ok(SeaCucumber::Southbound),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Moves the herds once, returns `None` if no sea cucumber can move
+fn step(map: &Grid<Option<SeaCucumber>>) -> Option<Grid<Option<SeaCucumber>>> {
+    let mut next = Grid::filled(map.width(), map.height(), None);
+    let mut any_moved = false;
+
+    // eastbound go first, the map wraps around its edges
+    for point in map.points() {
+        let destination = map.wrapping_offset(point, (1, 0));
+        if map[point] == Some(SeaCucumber::Eastbound) {
+            if map[destination].is_none() {
+                next[destination] = Some(SeaCucumber::Eastbound);
+                any_moved = true;
+            } else {
+                next[point] = Some(SeaCucumber::Eastbound);
+            }
+        }
+    }
+    // southbound
+    for point in map.points() {
+        let destination = map.wrapping_offset(point, (0, 1));
+        if map[point] == Some(SeaCucumber::Southbound) {
+            if next[destination].is_none() && map[destination] != Some(SeaCucumber::Southbound) {
+                next[destination] = Some(SeaCucumber::Southbound);
+                any_moved = true;
+            } else {
+                next[point] = Some(SeaCucumber::Southbound);
+            }
+        }
+    }
+
+    any_moved.then_some(next)
+}
+
+/// Runs steps until no sea cucumber moves and returns the number of the first such step
+fn steps_until_stopped(map: &Grid<Option<SeaCucumber>>) -> u64 {
+    let mut map = map.clone();
+    let mut step_index = 1;
+    while let Some(next) = step(&map) {
+        map = next;
+        step_index += 1;
+    }
+    step_index
+}
+
+/// Positions of the herds after a step
+pub struct SeaCucumberFrame {
+    step: u64,
+    map: Grid<Option<SeaCucumber>>,
+}
+
+impl Frame for SeaCucumberFrame {
+    fn render(&self) -> String {
+        let map = self.map.render(|cell| match cell {
+            Some(SeaCucumber::Eastbound) => '>',
+            Some(SeaCucumber::Southbound) => 'v',
+            None => '.',
+        });
+        format!("Step {}\n{}", self.step, map)
+    }
+}
+
+impl ToImage for SeaCucumberFrame {
+    /// Eastbound herd in orange, southbound herd in blue
+    fn to_image(&self) -> Image {
+        let palette = vec![DARK, [255, 140, 0], [80, 170, 255]];
+        Image::from_grid(&self.map, palette, |cell| match cell {
+            None => 0,
+            Some(SeaCucumber::Eastbound) => 1,
+            Some(SeaCucumber::Southbound) => 2,
+        })
+    }
+}
+
+/// Initial positions and each step in which a sea cucumber moves
+pub fn frames(map: &Grid<Option<SeaCucumber>>) -> impl Iterator<Item = SeaCucumberFrame> {
+    let initial = SeaCucumberFrame {
+        step: 0,
+        map: map.clone(),
+    };
+    std::iter::successors(Some(initial), |frame| {
+        step(&frame.map).map(|map| SeaCucumberFrame {
+            step: frame.step + 1,
+            map,
+        })
+    })
+}
+
+pub struct SeaCucumbers;
+
+impl Solution for SeaCucumbers {
+    const DAY: u8 = 25;
+
+    type Input = Grid<Option<SeaCucumber>>;
+    type Answer1 = u64;
+    type Answer2 = &'static str;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "`>`, `v` or `.`", |c| match c {
+            '.' => Some(None),
+            _ => c.try_into().ok().map(Some),
+        })
+    }
+
+    /// First step on which no sea cucumbers move
+    fn part_1(&self, map: &Self::Input) -> Self::Answer1 {
+        steps_until_stopped(map)
+    }
+
+    /// There is no puzzle in part 2 of the last day
+    fn part_2(&self, _map: &Self::Input) -> Self::Answer2 {
+        "Merry Christmas!"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn frames_until_stopped() {
+        let input = fs::read_to_string("test_inputs/day_25_sea_cucumber.txt").unwrap();
+        let map = SeaCucumbers.parse(&input).unwrap();
+        let frames: Vec<SeaCucumberFrame> = frames(&map).collect();
+
+        // nothing moves on step 58
+        assert_eq!(frames.len(), 58);
+        assert_eq!(frames[0].render(), format!("Step 0\n{}", input.trim_end()));
+        assert!(frames[57].render().starts_with("Step 57\n..>>v>vv..\n"));
+    }
+}
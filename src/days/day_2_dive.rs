@@ -0,0 +1,371 @@
+//! Solution to an Advent of Code problem, day 2, 2021
+//! https://adventofcode.com/2021/day/2
+//!
+//! Besides the puzzle's flat lists of commands, input can be a dive plan:
+//! ```text
+//! # survey the trench
+//! down 2
+//! repeat 3 {
+//!     forward 5
+//!     backward 1
+//! }
+//! ```
+//! `#` starts a comment, blank lines are skipped, and `repeat <count> { ... }` blocks,
+//! which may be nested, repeat the commands between their lines.
+use crate::error::{parse_number_in, ParseError};
+use crate::output::Count;
+use crate::Solution;
+use std::io::{self, Write};
+use std::iter;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Move {
+    Up(i64),
+    Down(i64),
+    Forward(i64),
+    Backward(i64),
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (command, arg) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new(s, "expected `<command> <number>`"))?;
+        let arg = parse_number_in(s, arg)?;
+        match command {
+            "up" => Ok(Move::Up(arg)),
+            "down" => Ok(Move::Down(arg)),
+            "forward" => Ok(Move::Forward(arg)),
+            "backward" => Ok(Move::Backward(arg)),
+            _ => Err(ParseError::new(
+                s,
+                "expected one of `up`, `down`, `forward`, `backward`",
+            )),
+        }
+    }
+}
+
+/// Most commands a plan may expand to, as nested `repeat` blocks multiply quickly
+pub const MAX_COMMANDS: usize = 1 << 20;
+
+/// A `repeat` block whose `}` has not been reached yet
+struct Block<'a> {
+    /// The `repeat` line and its number, for errors
+    text: &'a str,
+    line: usize,
+    count: usize,
+    commands: Vec<Move>,
+}
+
+/// Parses a `repeat <count> {` line, returns the count
+fn parse_repeat(s: &str) -> Result<usize, ParseError> {
+    let count = s
+        .strip_prefix("repeat")
+        .and_then(|rest| rest.strip_suffix('{'))
+        .map(str::trim)
+        .ok_or_else(|| ParseError::new(s, "expected `repeat <count> {`"))?;
+    parse_number_in(s, count)
+}
+
+/// Parses a dive plan, see the module documentation. `repeat` blocks are expanded.
+/// Line numbers are added to errors.
+pub fn parse_plan(input: &str) -> Result<Vec<Move>, ParseError> {
+    let mut commands = vec![];
+    let mut blocks: Vec<Block> = vec![];
+    for (i, line) in input.lines().enumerate() {
+        let statement = line.split('#').next().unwrap_or_default().trim();
+        if statement.is_empty() {
+            continue;
+        }
+
+        if statement == "}" {
+            let block = blocks
+                .pop()
+                .ok_or_else(|| ParseError::new(statement, "`}` without `repeat`").at_line(i + 1))?;
+            let outer = blocks
+                .last_mut()
+                .map_or(&mut commands, |outer| &mut outer.commands);
+            let repeated = block
+                .commands
+                .len()
+                .checked_mul(block.count)
+                .filter(|&repeated| repeated <= MAX_COMMANDS.saturating_sub(outer.len()))
+                .ok_or_else(|| {
+                    ParseError::new(
+                        block.text,
+                        format!("plan expands to more than {} commands", MAX_COMMANDS),
+                    )
+                    .at_line(block.line)
+                })?;
+            outer.extend(block.commands.iter().cycle().take(repeated));
+        } else if statement.split_whitespace().next() == Some("repeat") {
+            blocks.push(Block {
+                text: statement,
+                line: i + 1,
+                count: parse_repeat(statement).map_err(|err| err.at_line(i + 1))?,
+                commands: vec![],
+            });
+        } else {
+            let command = statement
+                .parse::<Move>()
+                .map_err(|err| err.at_line(i + 1))?;
+            blocks
+                .last_mut()
+                .map_or(&mut commands, |block| &mut block.commands)
+                .push(command);
+        }
+    }
+
+    match blocks.pop() {
+        Some(block) => Err(ParseError::new(block.text, "`repeat` without `}`").at_line(block.line)),
+        None => Ok(commands),
+    }
+}
+
+/// Position of the submarine. `aim` stays 0 in models without aiming.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    pub x: i64,
+    pub depth: i64,
+    pub aim: i64,
+}
+
+/// How commands move the submarine
+pub trait MovementModel {
+    /// State after executing `command` in `state`, None if it does not fit in an `i64`
+    fn execute(&self, state: State, command: Move) -> Option<State>;
+}
+
+/// Part 1: `up` and `down` change the depth, `forward` and `backward` the horizontal position
+pub struct Classic;
+
+impl MovementModel for Classic {
+    fn execute(&self, state: State, command: Move) -> Option<State> {
+        Some(match command {
+            Move::Up(v) => State {
+                depth: state.depth.checked_sub(v)?,
+                ..state
+            },
+            Move::Down(v) => State {
+                depth: state.depth.checked_add(v)?,
+                ..state
+            },
+            Move::Forward(v) => State {
+                x: state.x.checked_add(v)?,
+                ..state
+            },
+            Move::Backward(v) => State {
+                x: state.x.checked_sub(v)?,
+                ..state
+            },
+        })
+    }
+}
+
+/// Part 2: `up` and `down` change the aim, `forward` and `backward` move along it
+pub struct Aim;
+
+impl MovementModel for Aim {
+    fn execute(&self, state: State, command: Move) -> Option<State> {
+        Some(match command {
+            Move::Up(v) => State {
+                aim: state.aim.checked_sub(v)?,
+                ..state
+            },
+            Move::Down(v) => State {
+                aim: state.aim.checked_add(v)?,
+                ..state
+            },
+            Move::Forward(v) => State {
+                x: state.x.checked_add(v)?,
+                depth: state.depth.checked_add(v.checked_mul(state.aim)?)?,
+                ..state
+            },
+            Move::Backward(v) => State {
+                x: state.x.checked_sub(v)?,
+                depth: state.depth.checked_sub(v.checked_mul(state.aim)?)?,
+                ..state
+            },
+        })
+    }
+}
+
+/// Submarine which executes commands with a movement model, starting at the surface
+pub struct Submarine<M> {
+    model: M,
+    state: State,
+}
+
+impl<M: MovementModel> Submarine<M> {
+    pub fn new(model: M) -> Submarine<M> {
+        Submarine {
+            model,
+            state: State::default(),
+        }
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    /// Executes a command, returns the new state. None if it overflows, the state is kept then.
+    pub fn execute(&mut self, command: Move) -> Option<State> {
+        self.state = self.model.execute(self.state, command)?;
+        Some(self.state)
+    }
+
+    /// State after each of the commands, up to the first one which overflows
+    pub fn trajectory<'a>(&'a mut self, commands: &'a [Move]) -> impl Iterator<Item = State> + 'a {
+        commands.iter().map_while(|&command| self.execute(command))
+    }
+
+    /// Final state after the commands, None if one of them overflows
+    pub fn run(&mut self, commands: &[Move]) -> Option<State> {
+        for &command in commands {
+            self.execute(command)?;
+        }
+        Some(self.state)
+    }
+}
+
+/// Writes a trajectory as CSV, with a header and a row for the starting state as step 0
+pub fn write_trajectory<W: Write, I: IntoIterator<Item = State>>(
+    out: &mut W,
+    start: State,
+    trajectory: I,
+) -> io::Result<()> {
+    writeln!(out, "step,x,depth,aim")?;
+    for (step, state) in iter::once(start).chain(trajectory).enumerate() {
+        writeln!(out, "{},{},{},{}", step, state.x, state.depth, state.aim)?;
+    }
+    Ok(())
+}
+
+pub struct Dive;
+
+impl Solution for Dive {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Move>;
+    type Answer1 = Count<i64>;
+    type Answer2 = Count<i64>;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_plan(input)
+    }
+
+    /// x * depth, without aiming
+    fn part_1(&self, commands: &Self::Input) -> Self::Answer1 {
+        let state = Submarine::new(Classic).run(commands);
+        Count(state.and_then(|state| state.x.checked_mul(state.depth)))
+    }
+
+    /// x * depth, with aiming
+    fn part_2(&self, commands: &Self::Input) -> Self::Answer2 {
+        let state = Submarine::new(Aim).run(commands);
+        Count(state.and_then(|state| state.x.checked_mul(state.depth)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn trajectories() {
+        let input = fs::read_to_string("test_inputs/day_2_dive.txt").unwrap();
+        let commands = Dive.parse(&input).unwrap();
+
+        let mut submarine = Submarine::new(Aim);
+        let trajectory: Vec<State> = submarine.trajectory(&commands).collect();
+        assert_eq!(trajectory.len(), 6);
+        assert_eq!(
+            trajectory[2],
+            State {
+                x: 13,
+                depth: 40,
+                aim: 5
+            }
+        );
+        assert_eq!(submarine.state(), trajectory[5]);
+
+        let mut csv = vec![];
+        let mut submarine = Submarine::new(Classic);
+        let start = submarine.state();
+        write_trajectory(&mut csv, start, submarine.trajectory(&commands[..2])).unwrap();
+        assert_eq!(
+            String::from_utf8(csv).unwrap(),
+            "step,x,depth,aim\n0,0,0,0\n1,5,0,0\n2,5,5,0\n"
+        );
+    }
+
+    #[test]
+    fn overflows() {
+        let deep = [Move::Down(i64::MAX), Move::Forward(2)];
+        assert_eq!(Dive.part_1(&deep.to_vec()).to_string(), "overflow");
+        assert_eq!(Dive.part_2(&deep.to_vec()), Count(None));
+        assert_eq!(Submarine::new(Aim).run(&deep), None);
+
+        // the trajectory stops before the command which overflows
+        let mut submarine = Submarine::new(Classic);
+        let far = [Move::Forward(1), Move::Forward(i64::MAX), Move::Down(1)];
+        assert_eq!(submarine.trajectory(&far).count(), 1);
+        assert_eq!(submarine.state().x, 1);
+    }
+
+    #[test]
+    fn plans() {
+        let plan = "# survey\ndown 2\n\nrepeat 2 {\n    forward 5 # ahead\n    repeat 3 {\n        backward 1\n    }\n}\nup 1\n";
+        let commands = parse_plan(plan).unwrap();
+        assert_eq!(commands.len(), 10);
+        assert_eq!(
+            &commands[..6],
+            [
+                Move::Down(2),
+                Move::Forward(5),
+                Move::Backward(1),
+                Move::Backward(1),
+                Move::Backward(1),
+                Move::Forward(5)
+            ]
+        );
+        assert_eq!(
+            Submarine::new(Aim).run(&commands),
+            Some(State {
+                x: 4,
+                depth: 8,
+                aim: 1
+            })
+        );
+
+        let error = |plan: &str| parse_plan(plan).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 1\n\nforward\n"),
+            "line 3: expected `<command> <number>` (`forward`)"
+        );
+        assert_eq!(
+            error("down 1\nrepeat x {\n}"),
+            "line 2 column 8: expected a number, found `x` (`repeat x {`)"
+        );
+        assert_eq!(
+            error("repeat 2 {\nup 1\n"),
+            "line 1: `repeat` without `}` (`repeat 2 {`)"
+        );
+        assert_eq!(error("up 1\n}\n"), "line 2: `}` without `repeat` (`}`)");
+        assert_eq!(
+            error("repeat 1024 {\nrepeat 1025 {\nup 1\n}\n}"),
+            "line 1: plan expands to more than 1048576 commands (`repeat 1024 {`)"
+        );
+        assert_eq!(
+            error("up 1\nup 1\nrepeat 9223372036854775807 {\nup 1\nup 1\n}"),
+            "line 3: plan expands to more than 1048576 commands (`repeat 9223372036854775807 {`)"
+        );
+        assert!(parse_plan("repeat 18446744073709551615 {\n}\n")
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -0,0 +1,310 @@
+//! Solution to an Advent of Code problem, day 3, 2021
+//! https://adventofcode.com/2021/day/3
+//!
+//! Numbers of the report are packed into 64 bit words, one word each for reports up to 64 digits
+//! wide, and kept sorted. Numbers which start with the same digits are then next to each other,
+//! so the rating filters narrow a range with a binary search per digit instead of scanning
+//! the remaining numbers.
+use crate::error::ParseError;
+use crate::Solution;
+use std::fmt;
+use std::ops::Range;
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// Unsigned number of any width, as 64 bit words, most significant first
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Number(pub Vec<u64>);
+
+impl Number {
+    /// Product of two numbers, as wide as both together
+    pub fn product(&self, other: &Number) -> Number {
+        let mut product = vec![0; self.0.len() + other.0.len()];
+        for (i, &a) in self.0.iter().rev().enumerate() {
+            let mut carry = 0;
+            for (j, &b) in other.0.iter().rev().enumerate() {
+                let digit = product.len() - 1 - i - j;
+                let sum = a as u128 * b as u128 + product[digit] as u128 + carry;
+                product[digit] = sum as u64;
+                carry = sum >> WORD_BITS;
+            }
+            product[self.0.len() - 1 - i] = carry as u64;
+        }
+        Number(product)
+    }
+}
+
+impl fmt::Display for Number {
+    /// In decimal
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // largest power of 10 in a u64
+        const CHUNK: u128 = 10_000_000_000_000_000_000;
+        let mut words: Vec<u64> = self
+            .0
+            .iter()
+            .copied()
+            .skip_while(|&word| word == 0)
+            .collect();
+        // groups of 19 decimal digits, least significant first
+        let mut chunks = vec![];
+        while !words.is_empty() {
+            let mut remainder = 0;
+            for word in &mut words {
+                let value = (remainder << WORD_BITS) | *word as u128;
+                *word = (value / CHUNK) as u64;
+                remainder = value % CHUNK;
+            }
+            chunks.push(remainder as u64);
+            let zeros = words.iter().take_while(|&&word| word == 0).count();
+            words.drain(..zeros);
+        }
+
+        match chunks.split_last() {
+            None => write!(f, "0"),
+            Some((highest, rest)) => {
+                write!(f, "{}", highest)?;
+                rest.iter()
+                    .rev()
+                    .try_for_each(|chunk| write!(f, "{:019}", chunk))
+            }
+        }
+    }
+}
+
+/// Diagnostic report of binary numbers with the same number of digits
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    /// Binary digits of each number
+    width: usize,
+    /// Words of each number
+    words: usize,
+    /// Numbers in ascending order, `words` words each
+    packed: Vec<u64>,
+}
+
+impl Report {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn len(&self) -> usize {
+        self.packed.len() / self.words
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packed.is_empty()
+    }
+
+    /// Words of the `i`th smallest number
+    fn number(&self, i: usize) -> &[u64] {
+        &self.packed[i * self.words..(i + 1) * self.words]
+    }
+
+    /// Word and bit of a column in the words of a number, column 0 being the leftmost digit
+    fn bit(&self, column: usize) -> (usize, u64) {
+        let position = self.words * WORD_BITS - self.width + column;
+        (
+            position / WORD_BITS,
+            1 << (WORD_BITS - 1 - position % WORD_BITS),
+        )
+    }
+
+    /// Number of ones in each column, counted in one pass over the report
+    pub fn column_counts(&self) -> Vec<usize> {
+        let mut counts = vec![0; self.words * WORD_BITS];
+        for number in self.packed.chunks_exact(self.words) {
+            for (i, &word) in number.iter().enumerate() {
+                let mut word = word;
+                while word != 0 {
+                    let zeros = word.leading_zeros() as usize;
+                    counts[i * WORD_BITS + zeros] += 1;
+                    word &= !(1 << (WORD_BITS - 1 - zeros));
+                }
+            }
+        }
+        counts.split_off(self.words * WORD_BITS - self.width)
+    }
+
+    /// Numbers in `range` with a 1 in `column`, given that they all share the digits before it
+    fn ones(&self, range: &Range<usize>, column: usize) -> Range<usize> {
+        let (word, bit) = self.bit(column);
+        let (mut low, mut high) = (range.start, range.end);
+        while low < high {
+            let middle = (low + high) / 2;
+            if self.number(middle)[word] & bit != 0 {
+                high = middle;
+            } else {
+                low = middle + 1;
+            }
+        }
+        low..range.end
+    }
+
+    /// Keeps the numbers with the most common digit, or the least common one, in each column
+    /// until one is left. On ties, ones are kept for the most common digit and zeros for
+    /// the least common one.
+    pub fn rating(&self, most_common: bool) -> Number {
+        let mut range = 0..self.len();
+        for column in 0..self.width {
+            if range.len() <= 1 {
+                break;
+            }
+            let ones = self.ones(&range, column);
+            let zeros = range.start..ones.start;
+            let keep_ones = if most_common {
+                ones.len() >= zeros.len()
+            } else {
+                ones.len() < zeros.len()
+            };
+            // if all numbers have the same digit, it is both the most and the least common one
+            range = match (keep_ones, ones.is_empty(), zeros.is_empty()) {
+                (true, false, _) | (false, _, true) => ones,
+                _ => zeros,
+            };
+        }
+        Number(self.number(range.start).to_vec())
+    }
+}
+
+pub struct BinaryDiagnostic;
+
+impl Solution for BinaryDiagnostic {
+    const DAY: u8 = 3;
+
+    type Input = Report;
+    type Answer1 = Number;
+    type Answer2 = Number;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let width = input.lines().next().map(str::len).unwrap_or(0);
+        if width == 0 {
+            return Err(ParseError::new(input, "expected a binary number").at_line(1));
+        }
+
+        let mut report = Report {
+            width,
+            words: width.div_ceil(WORD_BITS),
+            packed: vec![],
+        };
+        for (i, line) in input.lines().enumerate() {
+            if line.len() != width {
+                return Err(
+                    ParseError::new(line, format!("expected {} binary digits", width))
+                        .at_line(i + 1),
+                );
+            }
+            let start = report.packed.len();
+            report.packed.resize(start + report.words, 0);
+            for (column, digit) in line.bytes().enumerate() {
+                match digit {
+                    b'0' => {}
+                    b'1' => {
+                        let (word, bit) = report.bit(column);
+                        report.packed[start + word] |= bit;
+                    }
+                    _ => {
+                        return Err(ParseError::new(line, "expected a binary digit")
+                            .at_line(i + 1)
+                            .at_column(column + 1))
+                    }
+                }
+            }
+        }
+
+        if report.words == 1 {
+            report.packed.sort_unstable();
+        } else {
+            let mut numbers: Vec<&[u64]> = report.packed.chunks_exact(report.words).collect();
+            numbers.sort_unstable();
+            report.packed = numbers.concat();
+        }
+        Ok(report)
+    }
+
+    /// Power consumption
+    fn part_1(&self, report: &Self::Input) -> Self::Answer1 {
+        let mut gamma_rate = vec![0; report.words];
+        let mut epsilon_rate = vec![0; report.words];
+        for (column, ones) in report.column_counts().into_iter().enumerate() {
+            let (word, bit) = report.bit(column);
+            if ones > report.len() - ones {
+                gamma_rate[word] |= bit;
+            } else {
+                epsilon_rate[word] |= bit;
+            }
+        }
+
+        Number(gamma_rate).product(&Number(epsilon_rate))
+    }
+
+    /// Life rating
+    fn part_2(&self, report: &Self::Input) -> Self::Answer2 {
+        let oxygen_generator_rating = report.rating(true);
+        let co2_scrubber_rating = report.rating(false);
+
+        oxygen_generator_rating.product(&co2_scrubber_rating)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn numbers() {
+        assert_eq!(Number(vec![]).to_string(), "0");
+        assert_eq!(Number(vec![0, 22]).to_string(), "22");
+        assert_eq!(
+            Number(vec![u64::MAX]).product(&Number(vec![u64::MAX])),
+            Number(vec![u64::MAX - 1, 1])
+        );
+        // 2^128
+        assert_eq!(
+            Number(vec![1, 0, 0]).to_string(),
+            "340282366920938463463374607431768211456"
+        );
+    }
+
+    #[test]
+    fn packed_report() {
+        let input = fs::read_to_string("test_inputs/day_3_binary_diagnostic.txt").unwrap();
+        let report = BinaryDiagnostic.parse(&input).unwrap();
+        assert_eq!((report.width(), report.len()), (5, 12));
+        assert_eq!(report.column_counts(), [7, 5, 8, 7, 5]);
+        assert_eq!(report.rating(true), Number(vec![0b10111]));
+        assert_eq!(report.rating(false), Number(vec![0b01010]));
+
+        // the same numbers, with 70 leading zeros, span two words
+        let wide_input: String = input
+            .lines()
+            .map(|line| format!("{}{}\n", "0".repeat(70), line))
+            .collect();
+        let wide_report = BinaryDiagnostic.parse(&wide_input).unwrap();
+        assert_eq!(wide_report.width(), 75);
+        assert_eq!(wide_report.column_counts()[70..], [7, 5, 8, 7, 5]);
+        assert_eq!(wide_report.rating(false), Number(vec![0, 0b01010]));
+        assert_eq!(BinaryDiagnostic.part_2(&wide_report).to_string(), "230");
+        // epsilon rate is all ones but in the columns of the gamma rate
+        let epsilon_rate = (1u128 << 75) - 1 - 0b10110;
+        assert_eq!(
+            BinaryDiagnostic.part_1(&wide_report).to_string(),
+            (epsilon_rate * 0b10110).to_string()
+        );
+
+        assert_eq!(
+            BinaryDiagnostic
+                .parse("0101\n011\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2: expected 4 binary digits (`011`)"
+        );
+        assert_eq!(
+            BinaryDiagnostic
+                .parse("0101\n0121\n")
+                .unwrap_err()
+                .to_string(),
+            "line 2 column 3: expected a binary digit (`0121`)"
+        );
+    }
+}
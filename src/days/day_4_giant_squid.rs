@@ -0,0 +1,203 @@
+//! Solution to an Advent of Code problem, day 4, 2021
+//! https://adventofcode.com/2021/day/4
+use crate::error::{parse_number_in, ParseError};
+use crate::Solution;
+use std::convert::TryInto;
+
+#[derive(Debug, Clone)]
+pub struct BingoBoard {
+    board: [[u64; 5]; 5],
+    marks: [[bool; 5]; 5],
+    has_won: bool,
+}
+
+impl BingoBoard {
+    /// Parses 5 lines of 5 numbers.
+    /// Line numbers in errors are relative to the first line.
+    fn parse(lines: &[&str]) -> Result<BingoBoard, ParseError> {
+        let rows = lines
+            .iter()
+            .map(|line| {
+                line.split(' ')
+                    .filter(|v| !v.is_empty())
+                    .map(|n| parse_number_in::<u64>(line, n))
+                    .collect::<Result<Vec<u64>, ParseError>>()?
+                    .as_slice()
+                    .try_into()
+                    .map_err(|_| ParseError::new(line, "expected 5 numbers"))
+            })
+            .enumerate()
+            .map(|(i, row)| row.map_err(|err| err.at_line(i + 1)))
+            .collect::<Result<Vec<[u64; 5]>, ParseError>>()?;
+
+        Ok(BingoBoard {
+            board: rows
+                .as_slice()
+                .try_into()
+                .map_err(|_| ParseError::new(lines.join("\n").as_str(), "expected 5 rows"))?,
+            marks: Default::default(),
+            has_won: false,
+        })
+    }
+
+    fn mark(&mut self, drawn_number: u64) {
+        for i in 0..5 {
+            for j in 0..5 {
+                if self.board[i][j] == drawn_number {
+                    self.marks[i][j] = true;
+                }
+            }
+        }
+
+        self.has_won = self._calculate_has_won();
+    }
+
+    fn _calculate_has_won(&self) -> bool {
+        // by row
+        for i in 0..5 {
+            let mut all_in_row_marked = true;
+            for j in 0..5 {
+                all_in_row_marked = all_in_row_marked && self.marks[i][j];
+            }
+            if all_in_row_marked {
+                return true;
+            }
+        }
+
+        // by column
+        for j in 0..5 {
+            let mut all_in_column_marked = true;
+            for i in 0..5 {
+                all_in_column_marked = all_in_column_marked && self.marks[i][j];
+            }
+            if all_in_column_marked {
+                return true;
+            }
+        }
+
+        false
+    }
+
+    fn has_won(&self) -> bool {
+        self.has_won
+    }
+
+    fn sum_of_unmarked_numbers(&self) -> u64 {
+        let mut sum: u64 = 0;
+        for i in 0..5 {
+            for j in 0..5 {
+                if !self.marks[i][j] {
+                    sum += self.board[i][j];
+                }
+            }
+        }
+        sum
+    }
+}
+
+/// Plays bingo until every board has won.
+/// Returns scores of the boards in the order they won.
+fn winning_scores(drawn_numbers: &[u64], bingo_boards: &[BingoBoard]) -> Vec<u64> {
+    let mut bingo_boards = bingo_boards.to_vec();
+    let mut scores = vec![];
+
+    for drawn_number in drawn_numbers {
+        bingo_boards.iter_mut().for_each(|b| b.mark(*drawn_number));
+
+        let boards_won_in_this_round = bingo_boards.iter().filter(|b| b.has_won());
+        for b in boards_won_in_this_round {
+            scores.push(b.sum_of_unmarked_numbers() * drawn_number);
+        }
+        bingo_boards.retain(|b| !b.has_won());
+    }
+
+    scores
+}
+
+pub struct Bingo {
+    drawn_numbers: Vec<u64>,
+    bingo_boards: Vec<BingoBoard>,
+}
+
+pub struct GiantSquid;
+
+impl Solution for GiantSquid {
+    const DAY: u8 = 4;
+
+    type Input = Bingo;
+    type Answer1 = u64;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        // the last line may contain only whitespace
+        let lines = input.trim_end().lines().collect::<Vec<&str>>();
+        let first_line = lines.first().copied().unwrap_or("");
+
+        let drawn_numbers = first_line
+            .split(',')
+            .map(|v| parse_number_in::<u64>(first_line, v))
+            .collect::<Result<Vec<u64>, ParseError>>()
+            .map_err(|err| err.at_line(1))?;
+
+        // each board is preceded by an empty line
+        let bingo_boards = lines[1..]
+            .chunks(6)
+            .enumerate()
+            .map(|(board_index, board_lines)| {
+                let first_line_number = 1 + board_index * 6 + 2;
+                BingoBoard::parse(&board_lines[1..]).map_err(|mut err| {
+                    err.line = if err.line == 0 {
+                        first_line_number
+                    } else {
+                        first_line_number + err.line - 1
+                    };
+                    err
+                })
+            })
+            .collect::<Result<Vec<BingoBoard>, ParseError>>()?;
+        if winning_scores(&drawn_numbers, &bingo_boards).is_empty() {
+            return Err(
+                ParseError::new(first_line, "expected numbers which let a board win").at_line(1),
+            );
+        }
+
+        Ok(Bingo {
+            drawn_numbers,
+            bingo_boards,
+        })
+    }
+
+    /// Score of the first board to win
+    fn part_1(&self, bingo: &Self::Input) -> Self::Answer1 {
+        *winning_scores(&bingo.drawn_numbers, &bingo.bingo_boards)
+            .first()
+            .unwrap()
+    }
+
+    /// Score of the last board to win
+    fn part_2(&self, bingo: &Self::Input) -> Self::Answer2 {
+        *winning_scores(&bingo.drawn_numbers, &bingo.bingo_boards)
+            .last()
+            .unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn boards_must_win() {
+        let board = "\n\n1 2 3 4 5\n6 7 8 9 10\n11 12 13 14 15\n16 17 18 19 20\n21 22 23 24 25\n";
+        let bingo = GiantSquid.parse(&format!("1,2,3,4,5{}", board)).unwrap();
+        assert_eq!(GiantSquid.part_1(&bingo), (6..=25).sum::<u64>() * 5);
+        assert_eq!(
+            GiantSquid
+                .parse(&format!("1,2{}", board))
+                .err()
+                .unwrap()
+                .to_string(),
+            "line 1: expected numbers which let a board win (`1,2`)"
+        );
+    }
+}
@@ -0,0 +1,106 @@
+//! Solution to an Advent of Code problem, day 5, 2021
+//! https://adventofcode.com/2021/day/5
+use crate::error::{parse_lines, parse_number_in, ParseError};
+use crate::Solution;
+use std::cmp::max;
+use std::cmp::min;
+use std::str::FromStr;
+
+#[derive(Clone, Copy)]
+pub struct Line {
+    x1: i64,
+    y1: i64,
+    x2: i64,
+    y2: i64,
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    /// Parses a line like `0,9 -> 5,9`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let syntax_error = || ParseError::new(s, "expected `x1,y1 -> x2,y2`");
+        let (from, to) = s.split_once(" -> ").ok_or_else(syntax_error)?;
+        let (x1, y1) = from.split_once(',').ok_or_else(syntax_error)?;
+        let (x2, y2) = to.split_once(',').ok_or_else(syntax_error)?;
+
+        Ok(Line {
+            x1: parse_number_in(s, x1)?,
+            y1: parse_number_in(s, y1)?,
+            x2: parse_number_in(s, x2)?,
+            y2: parse_number_in(s, y2)?,
+        })
+    }
+}
+
+impl Line {
+    fn is_diagonal(&self) -> bool {
+        self.x1 != self.x2 && self.y1 != self.y2
+    }
+
+    fn into_points(self) -> Box<dyn std::iter::Iterator<Item = (i64, i64)>> {
+        let y_min = min(self.y1, self.y2);
+        let y_max = max(self.y1, self.y2);
+        let x_min = min(self.x1, self.x2);
+        let x_max = max(self.x1, self.x2);
+
+        if self.x1 == self.x2 {
+            let x = self.x1;
+            Box::new((y_min..(y_max + 1)).map(move |y| (x, y)))
+        } else if self.y1 == self.y2 {
+            let y = self.y1;
+            Box::new((x_min..(x_max + 1)).map(move |x| (x, y)))
+        } else if (y_max - y_min) == (x_max - x_min) {
+            let x_direction: i64 = if self.x1 <= self.x2 { 1 } else { -1 };
+            let y_direction: i64 = if self.y1 <= self.y2 { 1 } else { -1 };
+            let k = x_direction * y_direction;
+            let y_start = if k < 0 { y_max } else { y_min };
+            Box::new((x_min..(x_max + 1)).map(move |x| (x, y_start + k * (x - x_min))))
+        } else {
+            Box::new(std::iter::empty())
+        }
+    }
+}
+
+fn count_overlapping_points<'a, I: Iterator<Item = &'a Line> + Clone>(lines: I) -> usize {
+    let x_max = lines.clone().map(|l| max(l.x1, l.x2)).max().unwrap();
+    let y_max = lines.clone().map(|l| max(l.y1, l.y2)).max().unwrap();
+    let capacity: usize = ((y_max + 1) * (x_max + 1)).try_into().unwrap();
+    let mut is_marked: Vec<bool> = vec![false; capacity];
+    let mut is_marked_more_than_once: Vec<bool> = vec![false; capacity];
+
+    for (x, y) in lines.flat_map(|line| line.into_points()) {
+        let index: usize = (y * (x_max + 1) + x).try_into().unwrap();
+        if !is_marked[index] {
+            is_marked[index] = true;
+        } else {
+            is_marked_more_than_once[index] = true;
+        }
+    }
+
+    is_marked_more_than_once.into_iter().filter(|v| *v).count()
+}
+
+pub struct HydrothermalVenture;
+
+impl Solution for HydrothermalVenture {
+    const DAY: u8 = 5;
+
+    type Input = Vec<Line>;
+    type Answer1 = usize;
+    type Answer2 = usize;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        parse_lines(input, Line::from_str)
+    }
+
+    /// Points where at least two horizontal or vertical lines overlap
+    fn part_1(&self, lines: &Self::Input) -> Self::Answer1 {
+        count_overlapping_points(lines.iter().filter(|line| !line.is_diagonal()))
+    }
+
+    /// Points where at least two lines overlap
+    fn part_2(&self, lines: &Self::Input) -> Self::Answer2 {
+        count_overlapping_points(lines.iter())
+    }
+}
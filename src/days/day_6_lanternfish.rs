@@ -0,0 +1,114 @@
+//! Solution to an Advent of Code problem, day 6, 2021
+//! https://adventofcode.com/2021/day/6
+use crate::error::{parse_number_in, ParseError};
+use crate::output::Count;
+use crate::Solution;
+
+/// Simulates fish population for given number of days and returns the fish count,
+/// `None` if it overflows
+fn lanternfish_count_after(initial_timers: &[usize], days: usize) -> Option<u64> {
+    // Fish internal counter histogram. Valid values: [0..8]
+    let mut histogram: Vec<u64> = vec![0; 9];
+    for &n in initial_timers {
+        histogram[n] += 1;
+    }
+
+    // Simulate
+    for _day in 1..days + 1 {
+        let number_of_fish_with_timer_at_0 = histogram.remove(0);
+        // timer of these fish resets to 6
+        histogram[6] = histogram[6].checked_add(number_of_fish_with_timer_at_0)?;
+        histogram.push(number_of_fish_with_timer_at_0); // offspring starts their timer at 8
+    }
+
+    histogram
+        .iter()
+        .try_fold(0u64, |count, &fish| count.checked_add(fish))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lanternfish {
+    /// Days simulated in part 1, at most `MAX_DAYS`
+    pub days_part_1: usize,
+    /// Days simulated in part 2, at most `MAX_DAYS`
+    pub days_part_2: usize,
+}
+
+impl Lanternfish {
+    /// Most days which can be set in `config`, the counts of the puzzle overflow after about 450
+    pub const MAX_DAYS: usize = 10_000;
+
+    /// Parameters of the puzzle, others can be set with `config`
+    pub const PUZZLE: Lanternfish = Lanternfish {
+        days_part_1: 80,
+        days_part_2: 256,
+    };
+}
+
+impl Solution for Lanternfish {
+    const DAY: u8 = 6;
+
+    type Input = Vec<usize>;
+    type Answer1 = Count;
+    type Answer2 = Count;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.lines().next().unwrap_or("");
+        line.split(',')
+            .map(|v| match parse_number_in::<usize>(line, v)? {
+                timer if timer <= 8 => Ok(timer),
+                _ => Err(ParseError::new(
+                    line,
+                    format!("timer must be between 0 and 8, found {}", v),
+                )),
+            })
+            .collect::<Result<Vec<usize>, ParseError>>()
+            .map_err(|err| err.at_line(1))
+    }
+
+    /// Lanternfish count after `days_part_1` days (80)
+    fn part_1(&self, numbers: &Self::Input) -> Self::Answer1 {
+        Count(lanternfish_count_after(numbers, self.days_part_1))
+    }
+
+    /// Lanternfish count after `days_part_2` days (256)
+    fn part_2(&self, numbers: &Self::Input) -> Self::Answer2 {
+        Count(lanternfish_count_after(numbers, self.days_part_2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::generate::Rng;
+
+    /// Simulates every fish on its own
+    fn brute_force_count_after(initial_timers: &[usize], days: usize) -> u64 {
+        let mut timers = initial_timers.to_vec();
+        for _day in 0..days {
+            let new_fish = timers.iter().filter(|&&timer| timer == 0).count();
+            for timer in timers.iter_mut() {
+                *timer = if *timer == 0 { 6 } else { *timer - 1 };
+            }
+            timers.extend(std::iter::repeat_n(8, new_fish));
+        }
+        timers.len() as u64
+    }
+
+    #[test]
+    fn histogram_matches_brute_force() {
+        let mut rng = Rng::new(6);
+        for _ in 0..200 {
+            let timers: Vec<usize> = (0..rng.below(10)).map(|_| rng.below(9) as usize).collect();
+            let days = rng.below(70) as usize;
+            assert_eq!(
+                lanternfish_count_after(&timers, days),
+                Some(brute_force_count_after(&timers, days)),
+                "timers {:?} after {} days",
+                timers,
+                days
+            );
+        }
+        assert_eq!(lanternfish_count_after(&[3, 4, 3, 1, 2], 500), None);
+    }
+}
@@ -0,0 +1,57 @@
+//! Solution to an Advent of Code problem, day 7, 2021
+//! https://adventofcode.com/2021/day/7
+use crate::error::{parse_number_in, ParseError};
+use crate::Solution;
+
+fn cost_part_1<I: std::iter::Iterator<Item = i64>>(target: i64, initial_positions: I) -> i64 {
+    initial_positions.map(|v| (target - v).abs()).sum()
+}
+
+fn cost_part_2<I: std::iter::Iterator<Item = i64>>(target: i64, initial_positions: I) -> i64 {
+    initial_positions
+        .map(|v| (target - v).abs())
+        // https://en.wikipedia.org/wiki/1_%2B_2_%2B_3_%2B_4_%2B_%E2%8B%AF
+        // https://en.wikipedia.org/wiki/Triangular_number
+        .map(|n| (n * (n + 1)) / 2)
+        .sum()
+}
+
+pub struct TreacheryOfWhales;
+
+impl Solution for TreacheryOfWhales {
+    const DAY: u8 = 7;
+
+    type Input = Vec<i64>;
+    type Answer1 = i64;
+    type Answer2 = i64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        let line = input.lines().next().unwrap_or("");
+        line.split(',')
+            .map(|v| parse_number_in::<i64>(line, v))
+            .collect::<Result<Vec<i64>, ParseError>>()
+            .map_err(|err| err.at_line(1))
+    }
+
+    /// Minimal cost
+    fn part_1(&self, numbers: &Self::Input) -> Self::Answer1 {
+        let min = *numbers.iter().min().unwrap();
+        let max = *numbers.iter().max().unwrap();
+
+        (min..max + 1)
+            .map(|target| cost_part_1(target, numbers.iter().copied()))
+            .min()
+            .unwrap()
+    }
+
+    /// Minimal cost, when each step costs 1 more than the previous one
+    fn part_2(&self, numbers: &Self::Input) -> Self::Answer2 {
+        let min = *numbers.iter().min().unwrap();
+        let max = *numbers.iter().max().unwrap();
+
+        (min..max + 1)
+            .map(|target| cost_part_2(target, numbers.iter().copied()))
+            .min()
+            .unwrap()
+    }
+}
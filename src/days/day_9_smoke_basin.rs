@@ -0,0 +1,160 @@
+//! Solution to an Advent of Code problem, day 9, 2021
+//! https://adventofcode.com/2021/day/9
+use crate::error::ParseError;
+use crate::grid::Grid;
+use crate::image::{hue_palette, Image, ToImage, DARK};
+use crate::visualize::Frame;
+use crate::Solution;
+
+fn low_points(height_map: &Grid<u32>) -> Vec<(usize, usize)> {
+    height_map
+        .points()
+        .filter(|&point| {
+            height_map
+                .neighbors_4(point)
+                .all(|neighbor| height_map[neighbor] > height_map[point])
+        })
+        .collect::<Vec<(usize, usize)>>()
+}
+
+/// Basin index of each point, `None` for points not (yet) in a basin
+type Basins = Grid<Option<usize>>;
+
+/// Each low point starts a basin
+fn basins_of_low_points(height_map: &Grid<u32>) -> Basins {
+    let mut basins = Grid::filled(height_map.width(), height_map.height(), None);
+    for (basin_index, point) in low_points(height_map).into_iter().enumerate() {
+        basins[point] = Some(basin_index);
+    }
+    basins
+}
+
+/// Grows each basin by its neighbors lower than 9, returns false if no basin has grown
+fn grow(height_map: &Grid<u32>, basins: &mut Basins) -> bool {
+    let mut has_grown = false;
+    for point in height_map.points() {
+        if let Some(basin_index) = basins[point] {
+            for neighbor in height_map.neighbors_4(point) {
+                if height_map[neighbor] < 9 && basins[neighbor].is_none() {
+                    basins[neighbor] = Some(basin_index);
+                    has_grown = true;
+                }
+            }
+        };
+    }
+    has_grown
+}
+
+/// Basins after a round of growing, on the height map shared by all frames
+pub struct BasinFrame<'a> {
+    round: usize,
+    height_map: &'a Grid<u32>,
+    basins: Basins,
+}
+
+impl Frame for BasinFrame<'_> {
+    /// Basins as letters, `#` for height 9
+    fn render(&self) -> String {
+        let mut points = self.height_map.points();
+        let map = self.basins.render(|basin| {
+            let point = points.next().unwrap();
+            match basin {
+                Some(basin_index) => (b'a' + (basin_index % 26) as u8) as char,
+                None if self.height_map[point] == 9 => '#',
+                None => '.',
+            }
+        });
+        format!("Round {}\n{}", self.round, map)
+    }
+}
+
+impl ToImage for BasinFrame<'_> {
+    /// Basins colored by their index, height 9 in gray
+    fn to_image(&self) -> Image {
+        const BASIN_COLORS: usize = 64;
+        let mut palette = vec![DARK, [96, 96, 96]];
+        palette.extend(hue_palette(BASIN_COLORS));
+
+        let mut points = self.height_map.points();
+        Image::from_grid(&self.basins, palette, |basin| {
+            let point = points.next().unwrap();
+            match basin {
+                Some(basin_index) => (2 + basin_index % BASIN_COLORS) as u8,
+                None if self.height_map[point] == 9 => 1,
+                None => 0,
+            }
+        })
+    }
+}
+
+/// Low points, then the basins after each round of growing until they are complete
+pub fn frames(height_map: &Grid<u32>) -> impl Iterator<Item = BasinFrame<'_>> {
+    let mut basins = basins_of_low_points(height_map);
+    let mut round = 0;
+    std::iter::from_fn(move || {
+        if round > 0 && !grow(height_map, &mut basins) {
+            return None;
+        }
+        let frame = BasinFrame {
+            round,
+            height_map,
+            basins: basins.clone(),
+        };
+        round += 1;
+        Some(frame)
+    })
+}
+
+pub struct SmokeBasin;
+
+impl Solution for SmokeBasin {
+    const DAY: u8 = 9;
+
+    type Input = Grid<u32>;
+    type Answer1 = u32;
+    type Answer2 = u64;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    /// Sum of risk levels
+    fn part_1(&self, height_map: &Self::Input) -> Self::Answer1 {
+        low_points(height_map)
+            .iter()
+            .map(|&point| height_map[point] + 1)
+            .sum::<u32>()
+    }
+
+    /// Product of three largest basin sizes
+    fn part_2(&self, height_map: &Self::Input) -> Self::Answer2 {
+        let mut basins = basins_of_low_points(height_map);
+        while grow(height_map, &mut basins) {}
+
+        let mut basin_sizes = vec![0; basins.iter().flatten().max().map_or(0, |&max| max + 1)];
+        for &basin_index in basins.iter().flatten() {
+            basin_sizes[basin_index] += 1;
+        }
+        basin_sizes.sort_unstable();
+        basin_sizes.iter().rev().take(3).product::<u64>()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn frames_until_basins_are_complete() {
+        let input = fs::read_to_string("test_inputs/day_9_smoke_basin.txt").unwrap();
+        let height_map = SmokeBasin.parse(&input).unwrap();
+        let frames: Vec<BasinFrame> = frames(&height_map).collect();
+
+        assert!(frames[0].render().starts_with("Round 0\n.a###....b\n"));
+        let last = frames.last().unwrap().render();
+        assert!(last.starts_with(&format!("Round {}\naa###bbbbb\n", frames.len() - 1)));
+        assert!(!last.contains('.'));
+        assert_eq!(frames.last().unwrap().to_image().width(), 10);
+    }
+}
@@ -0,0 +1,26 @@
+//! Solutions of the individual days
+pub mod day_10_syntax_scoring;
+pub mod day_11_dumbo_octopus;
+pub mod day_12_passage_pathing;
+pub mod day_13_transparent_origami;
+pub mod day_14_extended_polymerization;
+pub mod day_15_chiton;
+pub mod day_16_packet_decoder;
+pub mod day_17_trick_shot;
+pub mod day_18_snailfish;
+pub mod day_19_beacon_scanner;
+pub mod day_1_sonar_sweep;
+pub mod day_20_trench_map;
+pub mod day_21_dirac_dice;
+pub mod day_22_reactor_reboot;
+pub mod day_23_amphipod;
+pub mod day_24_arithmetic_logic_unit;
+pub mod day_25_sea_cucumber;
+pub mod day_2_dive;
+pub mod day_3_binary_diagnostic;
+pub mod day_4_giant_squid;
+pub mod day_5_hydrothermal_venture;
+pub mod day_6_lanternfish;
+pub mod day_7_treachery_of_whales;
+pub mod day_8_seven_segment_search;
+pub mod day_9_smoke_basin;
@@ -0,0 +1,194 @@
+//! Errors reported when puzzle input cannot be parsed
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+/// Input that could not be parsed, with the location and the reason.
+///
+/// Parsers of single lines don't know where the line is,
+/// so `line` and `day` are filled in later, by `parse_lines` and by the runner.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParseError {
+    /// Day of the puzzle, 0 if not known (yet)
+    pub day: u8,
+    /// Line number, starting at 1. 0 if not known (yet)
+    pub line: usize,
+    /// Column number within the line, starting at 1
+    pub column: Option<usize>,
+    /// The offending text
+    pub text: String,
+    pub reason: String,
+}
+
+impl ParseError {
+    pub fn new<R: Into<String>>(text: &str, reason: R) -> ParseError {
+        ParseError {
+            day: 0,
+            line: 0,
+            column: None,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    /// Sets the line number (starting at 1), unless it is already known
+    pub fn at_line(mut self, line: usize) -> ParseError {
+        if self.line == 0 {
+            self.line = line;
+        }
+        self
+    }
+
+    /// Sets the column number (starting at 1), unless it is already known
+    pub fn at_column(mut self, column: usize) -> ParseError {
+        if self.column.is_none() {
+            self.column = Some(column);
+        }
+        self
+    }
+
+    pub fn for_day(mut self, day: u8) -> ParseError {
+        self.day = day;
+        self
+    }
+}
+
+impl Display for ParseError {
+    /// Output example:
+    /// day 22 line 14: expected `x=a..b` (`on x=10..12,y=10..12`)
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut location = vec![];
+        if self.day > 0 {
+            location.push(format!("day {}", self.day));
+        }
+        if self.line > 0 {
+            location.push(format!("line {}", self.line));
+        }
+        if let Some(column) = self.column {
+            location.push(format!("column {}", column));
+        }
+
+        if !location.is_empty() {
+            write!(f, "{}: ", location.join(" "))?;
+        }
+        write!(f, "{} (`{}`)", self.reason, self.text)
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses a number, e.g. `-15`
+pub fn parse_number<T: FromStr>(s: &str) -> Result<T, ParseError> {
+    s.parse::<T>()
+        .map_err(|_| ParseError::new(s, "expected a number"))
+}
+
+/// Parses a number, which is a part of a larger text, e.g. `15` in `forward 15`.
+/// Errors refer to the whole text, with the column where the number starts.
+pub fn parse_number_in<T: FromStr>(text: &str, number: &str) -> Result<T, ParseError> {
+    number.parse::<T>().map_err(|_| {
+        let err = ParseError::new(text, format!("expected a number, found `{}`", number));
+        match column_of(text, number) {
+            Some(column) => err.at_column(column),
+            None => err,
+        }
+    })
+}
+
+/// Column (starting at 1, counted in bytes) where `part` starts, if `part` is a slice of `text`
+pub fn column_of(text: &str, part: &str) -> Option<usize> {
+    let text_start = text.as_ptr() as usize;
+    let part_start = part.as_ptr() as usize;
+    if text_start <= part_start && part_start + part.len() <= text_start + text.len() {
+        Some(part_start - text_start + 1)
+    } else {
+        None
+    }
+}
+
+/// Parses each line of the input with `parse_line`.
+/// Line numbers are added to errors.
+pub fn parse_lines<'a, T, F: FnMut(&'a str) -> Result<T, ParseError>>(
+    input: &'a str,
+    mut parse_line: F,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| parse_line(line).map_err(|err| err.at_line(i + 1)))
+        .collect()
+}
+
+/// Parses a line of digits, e.g. `2199943210`. Column numbers are added to errors.
+pub fn parse_digits(line: &str) -> Result<Vec<u32>, ParseError> {
+    line.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .ok_or_else(|| ParseError::new(line, "expected a digit").at_column(i + 1))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(
+            ParseError::new("on x=10..12", "expected `x=a..b,y=a..b,z=a..b`")
+                .at_line(14)
+                .for_day(22)
+                .to_string(),
+            "day 22 line 14: expected `x=a..b,y=a..b,z=a..b` (`on x=10..12`)"
+        );
+        assert_eq!(
+            ParseError::new("12a4", "expected a digit")
+                .at_column(3)
+                .to_string(),
+            "column 3: expected a digit (`12a4`)"
+        );
+    }
+
+    #[test]
+    fn location_is_not_overwritten() {
+        let err = ParseError::new("x", "reason")
+            .at_line(3)
+            .at_column(2)
+            .at_line(5)
+            .at_column(7);
+        assert_eq!(err.line, 3);
+        assert_eq!(err.column, Some(2));
+    }
+
+    #[test]
+    fn parse_lines_adds_line_numbers() {
+        assert_eq!(
+            parse_lines("1\n2\n3", parse_number::<i64>).unwrap(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            parse_lines("1\n2\nthree", parse_number::<i64>)
+                .unwrap_err()
+                .line,
+            3
+        );
+    }
+
+    #[test]
+    fn number_in_text() {
+        let text = "forward 1x";
+        let err = parse_number_in::<i64>(text, &text[8..]).unwrap_err();
+        assert_eq!(err.column, Some(9));
+        assert_eq!(err.text, "forward 1x");
+        assert_eq!(parse_number_in::<i64>(text, "-5").unwrap(), -5);
+        assert_eq!(column_of(text, "1x"), None);
+    }
+
+    #[test]
+    fn digits() {
+        assert_eq!(parse_digits("2199").unwrap(), vec![2, 1, 9, 9]);
+        assert_eq!(parse_digits("21x9").unwrap_err().column, Some(3));
+    }
+}
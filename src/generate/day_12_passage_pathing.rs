@@ -0,0 +1,88 @@
+//! Cave systems for day 12
+use super::Rng;
+use std::collections::HashSet;
+
+/// Cave count of the published inputs, without `start` and `end`
+pub const DEFAULT_SIZE: usize = 11;
+
+/// Unique name of a cave, two or more letters: `aa`, `ab`, ..., or `AA`, `AB`, ... for big caves
+fn cave_name(index: usize, big: bool) -> String {
+    let first = if big { b'A' } else { b'a' };
+    let mut letters = vec![];
+    let mut rest = index;
+    while letters.len() < 2 || rest > 0 {
+        letters.push((first + (rest % 26) as u8) as char);
+        rest /= 26;
+    }
+    letters.iter().rev().collect()
+}
+
+/// Connections between `start`, `end` and `size` other caves, about a third of them big.
+/// All caves are reachable from `start`, and no two big caves are connected,
+/// so the number of paths is finite.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    // (name, is big)
+    let mut caves = vec![("start".to_string(), false)];
+    for index in 0..size {
+        let big = rng.chance(1.0 / 3.0);
+        caves.push((cave_name(index, big), big));
+    }
+    caves.push(("end".to_string(), false));
+
+    let mut connections: Vec<(usize, usize)> = vec![];
+    let mut connected = HashSet::new();
+    let mut connect = |from: usize, to: usize, connections: &mut Vec<(usize, usize)>| {
+        let big_caves = caves[from].1 && caves[to].1;
+        if from != to && !big_caves && connected.insert((from.min(to), from.max(to))) {
+            connections.push((from, to));
+        }
+    };
+
+    // a tree from `start`, every cave is connected to an earlier one
+    for cave in 1..caves.len() {
+        let candidates: Vec<usize> = (0..cave)
+            .filter(|&other| !caves[other].1 || !caves[cave].1)
+            .collect();
+        let other = *rng.choose(&candidates);
+        connect(other, cave, &mut connections);
+    }
+    // and some shortcuts
+    for _ in 0..size / 2 + 1 {
+        let from = rng.below(caves.len() as u64) as usize;
+        let to = rng.below(caves.len() as u64) as usize;
+        connect(from, to, &mut connections);
+    }
+
+    rng.shuffle(&mut connections);
+    connections
+        .iter()
+        .map(|&(from, to)| format!("{}-{}\n", caves[from].0, caves[to].0))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_12_passage_pathing::PassagePathing;
+    use crate::Solution;
+
+    #[test]
+    fn names() {
+        assert_eq!(cave_name(0, false), "aa");
+        assert_eq!(cave_name(27, true), "BB");
+        assert_eq!(cave_name(26 * 26, false), "baa");
+    }
+
+    #[test]
+    fn caves_are_connected() {
+        let mut rng = Rng::new(12);
+        for size in [0, 1, 5, 9] {
+            let input = generate(&mut rng, size);
+            let caves = PassagePathing.parse(&input).unwrap();
+            assert_eq!(caves.len(), size + 2);
+            let paths = PassagePathing.part_1(&caves);
+            assert!(paths >= 1);
+            assert!(PassagePathing.part_2(&caves) >= paths);
+        }
+    }
+}
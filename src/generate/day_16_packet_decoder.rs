@@ -0,0 +1,187 @@
+//! BITS transmissions for day 16
+use super::Rng;
+
+/// About the packet count of the published inputs
+pub const DEFAULT_SIZE: usize = 250;
+
+/// Deeper packets could overflow the stack of the recursive parser
+const MAX_DEPTH: usize = 16;
+const MAX_BIT_LENGTH: usize = (1 << 15) - 1;
+const MAX_SUBPACKET_COUNT: usize = (1 << 11) - 1;
+
+const SUM: u64 = 0;
+const PRODUCT: u64 = 1;
+const MINIMUM: u64 = 2;
+const MAXIMUM: u64 = 3;
+const LITERAL: u64 = 4;
+const GREATER_THAN: u64 = 5;
+const LESS_THAN: u64 = 6;
+const EQUAL_TO: u64 = 7;
+
+/// Hexadecimal transmission with the answers to both parts
+pub struct Transmission {
+    pub hex: String,
+    pub version_sum: u64,
+    pub value: u64,
+}
+
+struct Packet {
+    version: u64,
+    type_id: u64,
+    /// Value of a literal
+    literal: u64,
+    subpackets: Vec<Packet>,
+}
+
+impl Packet {
+    fn literal(rng: &mut Rng, max: u64) -> Packet {
+        Packet {
+            version: rng.below(8),
+            type_id: LITERAL,
+            literal: rng.below(max),
+            subpackets: vec![],
+        }
+    }
+
+    /// Random packet made of `packet_count` packets (fewer if it would get too deep).
+    /// Products only multiply small literals, so that values fit in a `u64`.
+    fn random(rng: &mut Rng, packet_count: usize, depth: usize) -> Packet {
+        let subpacket_count = packet_count - 1;
+        if subpacket_count == 0 {
+            return Packet::literal(rng, 1 << 20);
+        }
+
+        let version = rng.below(8);
+        if depth + 1 >= MAX_DEPTH {
+            let subpackets = (0..subpacket_count.min(MAX_SUBPACKET_COUNT))
+                .map(|_| Packet::literal(rng, 1 << 20))
+                .collect();
+            return Packet::operator(version, SUM, subpackets);
+        }
+        if subpacket_count <= 3 && rng.chance(0.3) {
+            let factors = (0..subpacket_count)
+                .map(|_| Packet::literal(rng, 16))
+                .collect();
+            return Packet::operator(version, PRODUCT, factors);
+        }
+        if subpacket_count >= 2 && rng.chance(0.15) {
+            let type_id = *rng.choose(&[GREATER_THAN, LESS_THAN, EQUAL_TO]);
+            let subpackets = rng
+                .split(subpacket_count, 2)
+                .into_iter()
+                .map(|count| Packet::random(rng, count, depth + 1))
+                .collect();
+            return Packet::operator(version, type_id, subpackets);
+        }
+
+        let type_id = *rng.choose(&[SUM, SUM, MINIMUM, MAXIMUM]);
+        let split_count = subpacket_count.min(2 + rng.below(7) as usize);
+        let subpackets = rng
+            .split(subpacket_count, split_count)
+            .into_iter()
+            .map(|count| Packet::random(rng, count, depth + 1))
+            .collect();
+        Packet::operator(version, type_id, subpackets)
+    }
+
+    fn operator(version: u64, type_id: u64, subpackets: Vec<Packet>) -> Packet {
+        Packet {
+            version,
+            type_id,
+            literal: 0,
+            subpackets,
+        }
+    }
+
+    fn version_sum(&self) -> u64 {
+        self.version + self.subpackets.iter().map(Packet::version_sum).sum::<u64>()
+    }
+
+    fn value(&self) -> u64 {
+        let mut values = self.subpackets.iter().map(Packet::value);
+        match self.type_id {
+            LITERAL => self.literal,
+            SUM => values.sum(),
+            PRODUCT => values.product(),
+            MINIMUM => values.min().unwrap(),
+            MAXIMUM => values.max().unwrap(),
+            GREATER_THAN => (values.next() > values.next()) as u64,
+            LESS_THAN => (values.next() < values.next()) as u64,
+            EQUAL_TO => (values.next() == values.next()) as u64,
+            _ => unreachable!("There are only 8 packet types"),
+        }
+    }
+
+    fn push_bits(bits: &mut Vec<bool>, value: u64, count: usize) {
+        bits.extend((0..count).rev().map(|i| (value >> i) & 1 == 1));
+    }
+
+    fn encode(&self, rng: &mut Rng, bits: &mut Vec<bool>) {
+        Packet::push_bits(bits, self.version, 3);
+        Packet::push_bits(bits, self.type_id, 3);
+        if self.type_id == LITERAL {
+            // groups of 4 bits, each but the last one prefixed with 1
+            let group_count = (64 - self.literal.leading_zeros() as usize)
+                .div_ceil(4)
+                .max(1);
+            for group in (0..group_count).rev() {
+                bits.push(group > 0);
+                Packet::push_bits(bits, self.literal >> (4 * group), 4);
+            }
+            return;
+        }
+
+        let mut contents = vec![];
+        for subpacket in &self.subpackets {
+            subpacket.encode(rng, &mut contents);
+        }
+        if contents.len() <= MAX_BIT_LENGTH && rng.chance(0.5) {
+            bits.push(false);
+            Packet::push_bits(bits, contents.len() as u64, 15);
+        } else {
+            bits.push(true);
+            Packet::push_bits(bits, self.subpackets.len() as u64, 11);
+        }
+        bits.extend(contents);
+    }
+}
+
+/// Transmission of a packet made of `size` packets (at least 1)
+pub fn generate(rng: &mut Rng, size: usize) -> Transmission {
+    let packet = Packet::random(rng, size.max(1), 0);
+    let mut bits = vec![];
+    packet.encode(rng, &mut bits);
+    // padded with zeros to whole hexadecimal digits
+    bits.resize(bits.len().div_ceil(4) * 4, false);
+    let hex = bits
+        .chunks(4)
+        .map(|digit| {
+            let digit = digit.iter().fold(0, |value, &bit| 2 * value + bit as u32);
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect();
+
+    Transmission {
+        hex,
+        version_sum: packet.version_sum(),
+        value: packet.value(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_16_packet_decoder::PacketDecoder;
+    use crate::Solution;
+
+    #[test]
+    fn answers_match() {
+        let mut rng = Rng::new(16);
+        for size in [1, 2, 3, 10, 100, 5000] {
+            let transmission = generate(&mut rng, size);
+            let packet = PacketDecoder.parse(&transmission.hex).unwrap();
+            assert_eq!(PacketDecoder.part_1(&packet), transmission.version_sum);
+            assert_eq!(PacketDecoder.part_2(&packet), transmission.value);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+//! Snailfish homework for day 18
+use super::Rng;
+
+/// Number count of the published inputs
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Reduced snailfish number: pairs nested at most 4 deep, regular numbers below 10
+fn snailfish_number(rng: &mut Rng, depth: usize, number: &mut String) {
+    // the outermost pair is at depth 1, pairs at depth 4 contain only regular numbers
+    if depth > 0 && (depth >= 4 || rng.chance(0.3)) {
+        number.push(char::from_digit(rng.below(10) as u32, 10).unwrap());
+        return;
+    }
+    number.push('[');
+    snailfish_number(rng, depth + 1, number);
+    number.push(',');
+    snailfish_number(rng, depth + 1, number);
+    number.push(']');
+}
+
+/// `size` reduced snailfish numbers, one per line
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut input = String::new();
+    for _ in 0..size {
+        snailfish_number(rng, 0, &mut input);
+        input.push('\n');
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_18_snailfish::Snailfish;
+    use crate::Solution;
+
+    #[test]
+    fn numbers_are_reduced() {
+        let input = generate(&mut Rng::new(18), 50);
+        for line in input.lines() {
+            let mut depth = 0;
+            for c in line.chars() {
+                match c {
+                    '[' => depth += 1,
+                    ']' => depth -= 1,
+                    _ => assert!(depth <= 4, "{} is not reduced", line),
+                }
+            }
+        }
+
+        let numbers = Snailfish.parse(&input).unwrap();
+        assert_eq!(numbers.len(), 50);
+        Snailfish.part_1(&numbers);
+        Snailfish.part_2(&numbers);
+    }
+}
@@ -0,0 +1,224 @@
+//! Scanner reports for day 19, generated from a known layout of scanners and beacons
+use super::Rng;
+use std::collections::HashSet;
+
+/// Scanner count of the published inputs
+pub const DEFAULT_SIZE: usize = 30;
+
+/// Scanners detect beacons at most this far away on each axis
+const RANGE: i64 = 1000;
+/// Beacons seen by a scanner and by the scanner it is placed next to
+const SHARED_BEACONS: usize = 12;
+/// Beacons added around each scanner, in addition to the shared ones
+const OWN_BEACONS: usize = 14;
+/// Scanners are at least this far apart on one axis
+const SPACING: i64 = 1000;
+
+type Point = [i64; 3];
+
+/// Scanner reports, with the layout they were generated from
+pub struct ScannerReports {
+    pub input: String,
+    /// Positions relative to scanner 0, in the orientation of scanner 0
+    pub scanner_positions: Vec<Point>,
+    /// Positions relative to scanner 0, each of them seen by at least one scanner
+    pub beacons: Vec<Point>,
+}
+
+impl ScannerReports {
+    /// Answer to part 1
+    pub fn beacon_count(&self) -> usize {
+        self.beacons.len()
+    }
+
+    /// Answer to part 2: largest Manhattan distance between two scanners
+    pub fn largest_distance(&self) -> i64 {
+        let positions = &self.scanner_positions;
+        positions
+            .iter()
+            .flat_map(|a| {
+                positions
+                    .iter()
+                    .map(move |b| (0..3).map(|i| (a[i] - b[i]).abs()).sum())
+            })
+            .max()
+            .unwrap_or(0)
+    }
+}
+
+/// The 24 orientations of a scanner, as rotation matrices
+fn rotations() -> Vec<[[i64; 3]; 3]> {
+    let permutations = [
+        [0, 1, 2],
+        [0, 2, 1],
+        [1, 0, 2],
+        [1, 2, 0],
+        [2, 0, 1],
+        [2, 1, 0],
+    ];
+    let mut rotations = vec![];
+    for permutation in permutations {
+        for signs in 0..8 {
+            let mut matrix = [[0; 3]; 3];
+            for row in 0..3 {
+                matrix[row][permutation[row]] = if signs & (1 << row) == 0 { 1 } else { -1 };
+            }
+            let determinant = (0..3)
+                .map(|i| {
+                    let (j, k) = ((i + 1) % 3, (i + 2) % 3);
+                    matrix[0][i] * (matrix[1][j] * matrix[2][k] - matrix[1][k] * matrix[2][j])
+                })
+                .sum::<i64>();
+            // mirror images have determinant -1
+            if determinant == 1 {
+                rotations.push(matrix);
+            }
+        }
+    }
+    rotations
+}
+
+fn rotate(matrix: &[[i64; 3]; 3], point: Point) -> Point {
+    [0, 1, 2].map(|row| (0..3).map(|i| matrix[row][i] * point[i]).sum())
+}
+
+fn in_range(scanner: Point, beacon: Point) -> bool {
+    (0..3).all(|i| (beacon[i] - scanner[i]).abs() <= RANGE)
+}
+
+/// Adds `count` new beacons at random points of the box between `min` and `max`
+fn add_beacons(
+    rng: &mut Rng,
+    beacons: &mut Vec<Point>,
+    seen: &mut HashSet<Point>,
+    min: Point,
+    max: Point,
+    count: usize,
+) {
+    let mut added = 0;
+    while added < count {
+        let beacon = [0, 1, 2].map(|i| rng.range(min[i]..=max[i]));
+        if seen.insert(beacon) {
+            beacons.push(beacon);
+            added += 1;
+        }
+    }
+}
+
+/// Reports of `size` scanners (at least 1). Each scanner after the first one is placed near
+/// an earlier one, so that they share at least 12 beacons, and reports in a random orientation.
+pub fn generate(rng: &mut Rng, size: usize) -> ScannerReports {
+    let mut scanner_positions: Vec<Point> = vec![[0, 0, 0]];
+    let mut beacons = vec![];
+    let mut seen = HashSet::new();
+    let around = |scanner: Point| {
+        (
+            [0, 1, 2].map(|i| scanner[i] - RANGE),
+            [0, 1, 2].map(|i| scanner[i] + RANGE),
+        )
+    };
+
+    let (min, max) = around([0, 0, 0]);
+    add_beacons(
+        rng,
+        &mut beacons,
+        &mut seen,
+        min,
+        max,
+        SHARED_BEACONS + OWN_BEACONS,
+    );
+    for _ in 1..size.max(1) {
+        // close enough to an earlier scanner to share a large volume,
+        // far enough from all of them to keep reports about as small as the published ones
+        let (neighbor, position) = loop {
+            let neighbor = *rng.choose(&scanner_positions);
+            let position = [0, 1, 2].map(|i| neighbor[i] + rng.range(-1100..=1100));
+            let spaced = scanner_positions
+                .iter()
+                .all(|other| (0..3).any(|i| (position[i] - other[i]).abs() >= SPACING));
+            if spaced {
+                break (neighbor, position);
+            }
+        };
+
+        let (neighbor_min, neighbor_max) = around(neighbor);
+        let (min, max) = around(position);
+        let shared_min = [0, 1, 2].map(|i| min[i].max(neighbor_min[i]));
+        let shared_max = [0, 1, 2].map(|i| max[i].min(neighbor_max[i]));
+        add_beacons(
+            rng,
+            &mut beacons,
+            &mut seen,
+            shared_min,
+            shared_max,
+            SHARED_BEACONS,
+        );
+        add_beacons(rng, &mut beacons, &mut seen, min, max, OWN_BEACONS);
+        scanner_positions.push(position);
+    }
+
+    let rotations = rotations();
+    let mut input = String::new();
+    for (index, &scanner) in scanner_positions.iter().enumerate() {
+        // scanner 0 defines the orientation
+        let rotation = if index == 0 {
+            &rotations[0]
+        } else {
+            rng.choose(&rotations)
+        };
+        let mut report: Vec<Point> = beacons
+            .iter()
+            .filter(|&&beacon| in_range(scanner, beacon))
+            .map(|&beacon| rotate(rotation, [0, 1, 2].map(|i| beacon[i] - scanner[i])))
+            .collect();
+        rng.shuffle(&mut report);
+
+        if index > 0 {
+            input.push('\n');
+        }
+        input.push_str(&format!("--- scanner {} ---\n", index));
+        for [x, y, z] in report {
+            input.push_str(&format!("{},{},{}\n", x, y, z));
+        }
+    }
+
+    ScannerReports {
+        input,
+        scanner_positions,
+        beacons,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_19_beacon_scanner::{BeaconScanner, Resolved};
+    use crate::Solution;
+
+    #[test]
+    fn orientations() {
+        let rotations = rotations();
+        assert_eq!(rotations.len(), 24);
+        assert_eq!(rotations[0], [[1, 0, 0], [0, 1, 0], [0, 0, 1]]);
+        let images: HashSet<Point> = rotations.iter().map(|r| rotate(r, [1, 2, 3])).collect();
+        assert_eq!(images.len(), 24);
+    }
+
+    #[test]
+    fn answers_match_layout() {
+        let mut rng = Rng::new(19);
+        for size in [1, 2, 6] {
+            let reports = generate(&mut rng, size);
+            let scanners = BeaconScanner::PUZZLE.parse(&reports.input).unwrap();
+            assert_eq!(scanners.len(), size);
+            assert_eq!(
+                BeaconScanner::PUZZLE.part_1(&scanners),
+                Resolved(Some(reports.beacon_count()))
+            );
+            assert_eq!(
+                BeaconScanner::PUZZLE.part_2(&scanners),
+                Resolved(Some(reports.largest_distance()))
+            );
+        }
+    }
+}
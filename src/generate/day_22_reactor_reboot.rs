@@ -0,0 +1,69 @@
+//! Reboot steps for day 22
+use super::Rng;
+
+/// Step count of the published inputs
+pub const DEFAULT_SIZE: usize = 420;
+
+/// Steps in the initialization region come first, as in the published inputs
+const INITIALIZATION_SHARE: usize = 20;
+
+fn step(on: bool, ranges: [(i64, i64); 3]) -> String {
+    let [(x1, x2), (y1, y2), (z1, z2)] = ranges;
+    format!(
+        "{} x={}..{},y={}..{},z={}..{}\n",
+        if on { "on" } else { "off" },
+        x1,
+        x2,
+        y1,
+        y2,
+        z1,
+        z2
+    )
+}
+
+/// `size` steps. About one in 20 is in the -50..50 initialization region, the others are large
+/// cuboids anywhere else within 100000 of the origin. The first step turns cubes on.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let initialization_steps = size.div_ceil(INITIALIZATION_SHARE);
+    (0..size)
+        .map(|i| {
+            let on = i == 0 || rng.chance(0.6);
+            let ranges = if i < initialization_steps {
+                [0, 1, 2].map(|_| {
+                    let start = rng.range(-50..=40);
+                    (start, start + rng.range(0..=(50 - start).min(50)))
+                })
+            } else {
+                // outside the initialization region, which only the first steps change
+                loop {
+                    let ranges = [0, 1, 2].map(|_| {
+                        let center = rng.range(-100_000..=100_000);
+                        let half_size = rng.range(1..=30_000);
+                        (center - half_size, center + half_size)
+                    });
+                    if ranges.iter().any(|&(start, end)| end < -50 || 50 < start) {
+                        break ranges;
+                    }
+                }
+            };
+            step(on, ranges)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_22_reactor_reboot::ReactorReboot;
+    use crate::Solution;
+
+    #[test]
+    fn steps_are_valid() {
+        let input = generate(&mut Rng::new(22), 40);
+        assert!(input.starts_with("on "));
+        let steps = ReactorReboot::PUZZLE.parse(&input).unwrap();
+        assert_eq!(steps.len(), 40);
+        assert!(ReactorReboot::PUZZLE.part_1(&steps) > 0);
+        ReactorReboot::PUZZLE.part_2(&steps);
+    }
+}
@@ -0,0 +1,49 @@
+//! Bingo subsystems for day 4
+use super::Rng;
+
+/// Board count of the published inputs
+pub const DEFAULT_SIZE: usize = 100;
+
+/// Numbers on the boards and drawn numbers are `0..NUMBER_COUNT`
+const NUMBER_COUNT: u64 = 100;
+
+/// All numbers in random order, followed by `size` boards of 25 different numbers.
+/// Every number is drawn, so every board wins eventually.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut drawn_numbers: Vec<u64> = (0..NUMBER_COUNT).collect();
+    rng.shuffle(&mut drawn_numbers);
+    let mut input = drawn_numbers
+        .iter()
+        .map(u64::to_string)
+        .collect::<Vec<String>>()
+        .join(",");
+    input.push('\n');
+
+    for _ in 0..size {
+        let mut numbers: Vec<u64> = (0..NUMBER_COUNT).collect();
+        rng.shuffle(&mut numbers);
+        input.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row: Vec<String> = row.iter().map(|number| format!("{:>2}", number)).collect();
+            input.push_str(&row.join(" "));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_4_giant_squid::GiantSquid;
+    use crate::Solution;
+
+    #[test]
+    fn boards_are_valid() {
+        let input = generate(&mut Rng::new(4), 20);
+        assert_eq!(input.lines().count(), 1 + 20 * 6);
+        let bingo = GiantSquid.parse(&input).unwrap();
+        GiantSquid.part_1(&bingo);
+        GiantSquid.part_2(&bingo);
+    }
+}
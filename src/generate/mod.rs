@@ -0,0 +1,135 @@
+//! Random puzzle inputs, for stress testing the solutions beyond the published input sizes
+//!
+//! Every generator takes a seeded `Rng` and a size, so an input can be reproduced from its seed.
+//! What the size counts depends on the day, e.g. bingo boards for day 4 or reboot steps for day 22.
+use std::ops::RangeInclusive;
+
+pub mod day_12_passage_pathing;
+pub mod day_16_packet_decoder;
+pub mod day_18_snailfish;
+pub mod day_19_beacon_scanner;
+pub mod day_22_reactor_reboot;
+pub mod day_4_giant_squid;
+
+/// Small, fast and reproducible random number generator (SplitMix64).
+/// Not suitable for anything but test data.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    /// Number in `0..n`
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Empty range");
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    /// Number in the range, both ends included
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = range.into_inner();
+        assert!(start <= end, "Empty range");
+        let span = end.abs_diff(start);
+        if span == u64::MAX {
+            self.next_u64() as i64
+        } else {
+            start.wrapping_add(self.below(span + 1) as i64)
+        }
+    }
+
+    /// True with the given probability
+    pub fn chance(&mut self, probability: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+    }
+
+    /// `total` split into `count` random parts of at least 1
+    pub fn split(&mut self, total: usize, count: usize) -> Vec<usize> {
+        assert!(
+            0 < count && count <= total,
+            "Cannot split {} into {} parts",
+            total,
+            count
+        );
+        let mut cuts: Vec<usize> = (1..count)
+            .map(|_| self.below((total - count + 1) as u64) as usize)
+            .collect();
+        cuts.push(0);
+        cuts.push(total - count);
+        cuts.sort_unstable();
+        cuts.windows(2).map(|pair| pair[1] - pair[0] + 1).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reproducible() {
+        let first: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next_u64()))
+            .collect();
+        let second: Vec<u64> = (0..5)
+            .scan(Rng::new(7), |rng, _| Some(rng.next_u64()))
+            .collect();
+        assert_eq!(first, second);
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn ranges() {
+        let mut rng = Rng::new(1);
+        let mut seen = [false; 7];
+        for _ in 0..1000 {
+            let value = rng.range(-3..=3);
+            assert!((-3..=3).contains(&value));
+            seen[(value + 3) as usize] = true;
+        }
+        assert!(seen.iter().all(|&seen| seen));
+        assert_eq!(rng.range(5..=5), 5);
+        rng.range(i64::MIN..=i64::MAX);
+    }
+
+    #[test]
+    fn splits() {
+        let mut rng = Rng::new(2);
+        for (total, count) in [(1, 1), (10, 3), (10, 10), (1000, 7)] {
+            let parts = rng.split(total, count);
+            assert_eq!(parts.len(), count);
+            assert_eq!(parts.iter().sum::<usize>(), total);
+            assert!(parts.iter().all(|&part| part >= 1));
+        }
+    }
+
+    #[test]
+    fn shuffles() {
+        let mut items: Vec<u32> = (0..100).collect();
+        Rng::new(3).shuffle(&mut items);
+        assert_ne!(items, (0..100).collect::<Vec<u32>>());
+        items.sort_unstable();
+        assert_eq!(items, (0..100).collect::<Vec<u32>>());
+    }
+}
@@ -0,0 +1,308 @@
+//! Two-dimensional maps, as found in many puzzle inputs
+use crate::error::ParseError;
+use std::fmt::Display;
+use std::ops::{Index, IndexMut};
+
+/// Offsets of the 4 neighbors sharing an edge: left, right, up, down
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (1, 0), (0, -1), (0, 1)];
+
+/// Offsets of the 8 neighbors sharing an edge or a corner
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, 0),
+    (1, 0),
+    (0, -1),
+    (0, 1),
+    (-1, -1),
+    (-1, 1),
+    (1, -1),
+    (1, 1),
+];
+
+/// Rectangular map, stored row by row in a single `Vec`.
+/// Points are `(x, y)`, `(0, 0)` is the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Grid from cells listed row by row
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Grid<T> {
+        assert_eq!(
+            width * height,
+            cells.len(),
+            "Cell count must be width * height"
+        );
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Grid with every cell set to `value`
+    pub fn filled(width: usize, height: usize, value: T) -> Grid<T>
+    where
+        T: Clone,
+    {
+        Grid::new(width, height, vec![value; width * height])
+    }
+
+    /// Parses a map with one character per cell, e.g.
+    /// ```text
+    /// 2199943210
+    /// 3987894921
+    /// ```
+    /// `expected` describes a valid cell in errors, e.g. "a digit".
+    pub fn parse<F: FnMut(char) -> Option<T>>(
+        input: &str,
+        expected: &str,
+        mut parse_cell: F,
+    ) -> Result<Grid<T>, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (i, line) in input.lines().enumerate() {
+            let mut row_width = 0;
+            for (column, c) in line.chars().enumerate() {
+                let cell = parse_cell(c).ok_or_else(|| {
+                    ParseError::new(line, format!("expected {}", expected))
+                        .at_line(i + 1)
+                        .at_column(column + 1)
+                })?;
+                cells.push(cell);
+                row_width += 1;
+            }
+            if *width.get_or_insert(row_width) != row_width {
+                return Err(
+                    ParseError::new(line, "expected rows of the same length").at_line(i + 1)
+                );
+            }
+            height += 1;
+        }
+
+        match width {
+            Some(width) if width > 0 => Ok(Grid::new(width, height, cells)),
+            _ => Err(ParseError::new(input, "expected a map")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            Some(&self.cells[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Row `y`, from left to right
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width)
+    }
+
+    /// All cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    /// All points, row by row
+    pub fn points(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Grid of the same size with `f` applied to every cell
+    pub fn map<U, F: FnMut(&T) -> U>(&self, f: F) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// Point at the given offset, if it is within the grid
+    pub fn offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let x = x.checked_add_signed(dx)?;
+        let y = y.checked_add_signed(dy)?;
+        if x < self.width && y < self.height {
+            Some((x, y))
+        } else {
+            None
+        }
+    }
+
+    /// Point at the given offset, wrapping around the edges (the grid is a torus)
+    pub fn wrapping_offset(
+        &self,
+        (x, y): (usize, usize),
+        (dx, dy): (isize, isize),
+    ) -> (usize, usize) {
+        (
+            (x as isize + dx).rem_euclid(self.width as isize) as usize,
+            (y as isize + dy).rem_euclid(self.height as isize) as usize,
+        )
+    }
+
+    /// Neighbors sharing an edge with the point, within the grid
+    pub fn neighbors_4(&self, point: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4
+            .iter()
+            .filter_map(move |&offset| self.offset(point, offset))
+    }
+
+    /// Neighbors sharing an edge or a corner with the point, within the grid
+    pub fn neighbors_8(&self, point: (usize, usize)) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8
+            .iter()
+            .filter_map(move |&offset| self.offset(point, offset))
+    }
+
+    /// Neighbors sharing an edge with the point, wrapping around the edges
+    pub fn wrapping_neighbors_4(
+        &self,
+        point: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_4
+            .iter()
+            .map(move |&offset| self.wrapping_offset(point, offset))
+    }
+
+    /// Neighbors sharing an edge or a corner with the point, wrapping around the edges
+    pub fn wrapping_neighbors_8(
+        &self,
+        point: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS_8
+            .iter()
+            .map(move |&offset| self.wrapping_offset(point, offset))
+    }
+
+    /// Renders the grid with one character per cell, rows separated by line breaks
+    pub fn render<F: FnMut(&T) -> char>(&self, mut render_cell: F) -> String {
+        self.rows()
+            .map(|row| row.iter().map(&mut render_cell).collect::<String>())
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "({}, {}) is outside the grid",
+            x,
+            y
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
+
+impl<T: Display> Display for Grid<T> {
+    /// Cells next to each other, rows separated by line breaks
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digits(input: &str) -> Result<Grid<u32>, ParseError> {
+        Grid::parse(input, "a digit", |c| c.to_digit(10))
+    }
+
+    #[test]
+    fn parse() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 2);
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.row(1), &[4, 5, 6]);
+        assert_eq!(grid.get((3, 0)), None);
+    }
+
+    #[test]
+    fn parse_errors() {
+        let err = digits("123\n4x6").unwrap_err();
+        assert_eq!((err.line, err.column), (2, Some(2)));
+        assert_eq!(err.reason, "expected a digit");
+        assert_eq!(digits("123\n45").unwrap_err().line, 2);
+        assert!(digits("").is_err());
+    }
+
+    #[test]
+    fn neighbors() {
+        let grid = Grid::filled(3, 3, 0);
+        assert_eq!(
+            grid.neighbors_4((0, 0)).collect::<Vec<_>>(),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(grid.neighbors_4((1, 1)).count(), 4);
+        assert_eq!(grid.neighbors_8((0, 0)).count(), 3);
+        assert_eq!(grid.neighbors_8((2, 1)).count(), 5);
+        assert_eq!(grid.neighbors_8((1, 1)).count(), 8);
+    }
+
+    #[test]
+    fn wrapping() {
+        let grid = Grid::filled(4, 3, 0);
+        assert_eq!(grid.wrapping_offset((3, 2), (1, 1)), (0, 0));
+        assert_eq!(grid.wrapping_offset((0, 0), (-1, -1)), (3, 2));
+        assert_eq!(
+            grid.wrapping_neighbors_4((0, 0)).collect::<Vec<_>>(),
+            vec![(3, 0), (1, 0), (0, 2), (0, 1)]
+        );
+        assert_eq!(grid.wrapping_neighbors_8((0, 0)).count(), 8);
+    }
+
+    #[test]
+    fn display() {
+        let grid = digits("123\n456").unwrap();
+        assert_eq!(grid.to_string(), "123\n456");
+        assert_eq!(
+            grid.render(|&d| if d % 2 == 0 { '#' } else { '.' }),
+            ".#.\n#.#"
+        );
+        assert_eq!(grid.map(|d| d * 2).row(0), &[2, 4, 6]);
+        assert_eq!(grid.points().nth(4), Some((1, 1)));
+    }
+}
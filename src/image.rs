@@ -0,0 +1,539 @@
+//! Images of grids, written as PNG or animated GIF without external libraries
+//!
+//! Both formats are written in their simplest valid form: PNG with indexed colors and
+//! uncompressed ("stored") deflate blocks, GIF with a local color table and LZW data for each frame.
+use crate::grid::Grid;
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+pub type Color = [u8; 3];
+
+/// Background of the Advent of Code website
+pub const DARK: Color = [15, 15, 35];
+/// Stars of the Advent of Code website
+pub const GOLD: Color = [255, 255, 102];
+
+/// Image with at most 256 colors, stored as palette indices row by row
+#[derive(Debug, Clone, PartialEq)]
+pub struct Image {
+    width: usize,
+    height: usize,
+    palette: Vec<Color>,
+    pixels: Vec<u8>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, palette: Vec<Color>, pixels: Vec<u8>) -> Image {
+        assert_eq!(
+            width * height,
+            pixels.len(),
+            "Pixel count must be width * height"
+        );
+        assert!(
+            (1..=256).contains(&palette.len()),
+            "Palette must have 1 to 256 colors"
+        );
+        assert!(
+            pixels.iter().all(|&pixel| (pixel as usize) < palette.len()),
+            "Pixels must be indices into the palette"
+        );
+        Image {
+            width,
+            height,
+            palette,
+            pixels,
+        }
+    }
+
+    /// One pixel per cell, `color_index` picks a color of the palette for a cell
+    pub fn from_grid<T, F: FnMut(&T) -> u8>(
+        grid: &Grid<T>,
+        palette: Vec<Color>,
+        color_index: F,
+    ) -> Image {
+        Image::new(
+            grid.width(),
+            grid.height(),
+            palette,
+            grid.iter().map(color_index).collect(),
+        )
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Each pixel becomes a `factor` x `factor` square
+    pub fn scaled(&self, factor: usize) -> Image {
+        let width = self.width * factor;
+        let height = self.height * factor;
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x / factor, y / factor)))
+            .map(|(x, y)| self.pixels[y * self.width + x])
+            .collect();
+        Image::new(width, height, self.palette.clone(), pixels)
+    }
+
+    /// Image centered on a larger canvas of the first palette color
+    fn padded(&self, width: usize, height: usize) -> Image {
+        let left = (width - self.width) / 2;
+        let top = (height - self.height) / 2;
+        let mut pixels = vec![0; width * height];
+        for y in 0..self.height {
+            let start = (top + y) * width + left;
+            pixels[start..start + self.width]
+                .copy_from_slice(&self.pixels[y * self.width..(y + 1) * self.width]);
+        }
+        Image::new(width, height, self.palette.clone(), pixels)
+    }
+}
+
+/// Anything that can be drawn as an image, e.g. a frame of a simulation
+pub trait ToImage {
+    fn to_image(&self) -> Image;
+}
+
+/// Lit cells in gold on a dark background
+impl ToImage for Grid<bool> {
+    fn to_image(&self) -> Image {
+        Image::from_grid(self, vec![DARK, GOLD], |&lit| lit as u8)
+    }
+}
+
+/// Digits in shades of gray, from black (0) to white (9 or more)
+impl ToImage for Grid<u32> {
+    fn to_image(&self) -> Image {
+        let palette = (0..10).map(|digit| [(digit * 255 / 9) as u8; 3]).collect();
+        Image::from_grid(self, palette, |&digit| digit.min(9) as u8)
+    }
+}
+
+/// `count` colors of different hues, neighbors in the list differ a lot
+pub fn hue_palette(count: usize) -> Vec<Color> {
+    (0..count)
+        .map(|i| {
+            // golden ratio steps spread the hues evenly
+            let hue = (i as f64 * 0.618_033_988_75).fract() * 6.0;
+            let rising = hue.fract();
+            let (r, g, b) = match hue as usize {
+                0 => (1.0, rising, 0.0),
+                1 => (1.0 - rising, 1.0, 0.0),
+                2 => (0.0, 1.0, rising),
+                3 => (0.0, 1.0 - rising, 1.0),
+                4 => (rising, 0.0, 1.0),
+                _ => (1.0, 0.0, 1.0 - rising),
+            };
+            // pastel shades, lighter than the background
+            let shade = |channel: f64| (64.0 + channel * 191.0) as u8;
+            [shade(r), shade(g), shade(b)]
+        })
+        .collect()
+}
+
+/// CRC-32 as used by PNG (and zip)
+fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &byte in bytes {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 {
+                (crc >> 1) ^ 0xedb8_8320
+            } else {
+                crc >> 1
+            };
+        }
+    }
+    !crc
+}
+
+fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in bytes {
+        a = (a + byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    (b << 16) | a
+}
+
+/// zlib stream with the data in uncompressed deflate blocks
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    // deflate, 32K window, no dictionary, fastest compression
+    let mut stream = vec![0x78, 0x01];
+    let mut blocks = data.chunks(0xffff).peekable();
+    if blocks.peek().is_none() {
+        stream.extend_from_slice(&[1, 0, 0, 0xff, 0xff]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        stream.push(is_final as u8);
+        let len = block.len() as u16;
+        stream.extend_from_slice(&len.to_le_bytes());
+        stream.extend_from_slice(&(!len).to_le_bytes());
+        stream.extend_from_slice(block);
+    }
+    stream.extend_from_slice(&adler32(data).to_be_bytes());
+    stream
+}
+
+fn png_chunk(png: &mut Vec<u8>, chunk_type: &[u8; 4], data: &[u8]) {
+    png.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = png.len();
+    png.extend_from_slice(chunk_type);
+    png.extend_from_slice(data);
+    let crc = crc32(&png[start..]);
+    png.extend_from_slice(&crc.to_be_bytes());
+}
+
+/// PNG file with 8 bit palette indices
+pub fn encode_png(image: &Image) -> Vec<u8> {
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+
+    let mut header = vec![];
+    header.extend_from_slice(&(image.width as u32).to_be_bytes());
+    header.extend_from_slice(&(image.height as u32).to_be_bytes());
+    // bit depth 8, indexed color, deflate, no filters, no interlacing
+    header.extend_from_slice(&[8, 3, 0, 0, 0]);
+    png_chunk(&mut png, b"IHDR", &header);
+
+    png_chunk(&mut png, b"PLTE", &image.palette.concat());
+
+    // each row starts with its filter type, 0 is none
+    let mut rows = Vec::with_capacity((image.width + 1) * image.height);
+    for row in image.pixels.chunks(image.width.max(1)) {
+        rows.push(0);
+        rows.extend_from_slice(row);
+    }
+    png_chunk(&mut png, b"IDAT", &zlib_stored(&rows));
+    png_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+/// Variable width codes, least significant bit first
+struct BitWriter {
+    bytes: Vec<u8>,
+    buffer: u32,
+    bit_count: u32,
+}
+
+impl BitWriter {
+    fn write(&mut self, code: u16, width: u32) {
+        self.buffer |= (code as u32) << self.bit_count;
+        self.bit_count += width;
+        while self.bit_count >= 8 {
+            self.bytes.push(self.buffer as u8);
+            self.buffer >>= 8;
+            self.bit_count -= 8;
+        }
+    }
+
+    fn finish(mut self) -> Vec<u8> {
+        if self.bit_count > 0 {
+            self.bytes.push(self.buffer as u8);
+        }
+        self.bytes
+    }
+}
+
+const LZW_MAX_CODE: u16 = 4096;
+
+/// GIF flavor of LZW: starts with a clear code, code width grows up to 12 bits,
+/// the table is cleared when it is full
+fn lzw_encode(min_code_size: u32, indices: &[u8]) -> Vec<u8> {
+    let clear_code = 1u16 << min_code_size;
+    let end_code = clear_code + 1;
+    let mut table: HashMap<(u16, u8), u16> = HashMap::new();
+    let mut next_code = end_code + 1;
+    let mut code_size = min_code_size + 1;
+    let mut writer = BitWriter {
+        bytes: vec![],
+        buffer: 0,
+        bit_count: 0,
+    };
+
+    writer.write(clear_code, code_size);
+    let mut prefix: Option<u16> = None;
+    for &index in indices {
+        let Some(code) = prefix else {
+            prefix = Some(index as u16);
+            continue;
+        };
+        if let Some(&longer) = table.get(&(code, index)) {
+            prefix = Some(longer);
+            continue;
+        }
+
+        writer.write(code, code_size);
+        if next_code < LZW_MAX_CODE {
+            table.insert((code, index), next_code);
+            next_code += 1;
+            // the decoder adds codes one step later, so it widens codes after reading this one
+            if next_code > (1 << code_size) && code_size < 12 {
+                code_size += 1;
+            }
+        } else {
+            writer.write(clear_code, code_size);
+            table.clear();
+            next_code = end_code + 1;
+            code_size = min_code_size + 1;
+        }
+        prefix = Some(index as u16);
+    }
+    if let Some(code) = prefix {
+        writer.write(code, code_size);
+        // the decoder still adds a code after the last one
+        if next_code < LZW_MAX_CODE {
+            next_code += 1;
+            if next_code > (1 << code_size) && code_size < 12 {
+                code_size += 1;
+            }
+        }
+    }
+    writer.write(end_code, code_size);
+    writer.finish()
+}
+
+/// Animated GIF, looping forever. Frames smaller than the largest one are centered.
+/// Fails if a frame is wider or higher than the 65535 pixels a GIF can hold.
+pub fn encode_gif(frames: &[Image], delay: Duration) -> io::Result<Vec<u8>> {
+    let width = frames.iter().map(Image::width).max().unwrap_or(0);
+    let height = frames.iter().map(Image::height).max().unwrap_or(0);
+    if width > u16::MAX as usize || height > u16::MAX as usize {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!(
+                "GIF frames are at most {} pixels wide and high, found {}x{}",
+                u16::MAX,
+                width,
+                height
+            ),
+        ));
+    }
+    let delay = (delay.as_millis() / 10).min(u16::MAX as u128) as u16;
+
+    let mut gif = b"GIF89a".to_vec();
+    gif.extend_from_slice(&(width as u16).to_le_bytes());
+    gif.extend_from_slice(&(height as u16).to_le_bytes());
+    // no global color table, background color, square pixels
+    gif.extend_from_slice(&[0, 0, 0]);
+    // loop forever
+    gif.extend_from_slice(b"\x21\xff\x0bNETSCAPE2.0\x03\x01\x00\x00\x00");
+
+    for frame in frames {
+        let frame = frame.padded(width, height);
+
+        // graphic control extension: delay in hundredths of a second
+        gif.extend_from_slice(&[0x21, 0xf9, 0x04, 0x00]);
+        gif.extend_from_slice(&delay.to_le_bytes());
+        gif.extend_from_slice(&[0x00, 0x00]);
+
+        // color tables have 2^n entries, n >= 1
+        let color_bits = (usize::BITS - (frame.palette.len() - 1).leading_zeros()).max(1);
+        gif.push(0x2c);
+        for value in [0, 0, width, height] {
+            gif.extend_from_slice(&(value as u16).to_le_bytes());
+        }
+        gif.push(0x80 | (color_bits - 1) as u8);
+        gif.extend_from_slice(&frame.palette.concat());
+        gif.resize(gif.len() + 3 * ((1 << color_bits) - frame.palette.len()), 0);
+
+        let min_code_size = color_bits.max(2);
+        gif.push(min_code_size as u8);
+        for block in lzw_encode(min_code_size, &frame.pixels).chunks(255) {
+            gif.push(block.len() as u8);
+            gif.extend_from_slice(block);
+        }
+        gif.push(0);
+    }
+
+    gif.push(0x3b);
+    Ok(gif)
+}
+
+pub fn write_png<P: AsRef<Path>>(path: P, image: &Image) -> io::Result<()> {
+    fs::write(path, encode_png(image))
+}
+
+pub fn write_gif<P: AsRef<Path>>(path: P, frames: &[Image], delay: Duration) -> io::Result<()> {
+    fs::write(path, encode_gif(frames, delay)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Reference decoder, as described in the GIF specification
+    fn lzw_decode(min_code_size: u32, bytes: &[u8]) -> Vec<u8> {
+        let clear_code = 1u16 << min_code_size;
+        let end_code = clear_code + 1;
+        let reset = || -> Vec<Vec<u8>> { (0..=end_code).map(|code| vec![code as u8]).collect() };
+        let mut table = reset();
+        let mut code_size = min_code_size + 1;
+        let mut previous: Option<u16> = None;
+        let mut decoded = vec![];
+        let (mut buffer, mut bit_count, mut bytes) = (0u32, 0, bytes.iter());
+        loop {
+            while bit_count < code_size {
+                buffer |= (*bytes.next().expect("missing end code") as u32) << bit_count;
+                bit_count += 8;
+            }
+            let code = (buffer & ((1 << code_size) - 1)) as u16;
+            buffer >>= code_size;
+            bit_count -= code_size;
+
+            if code == clear_code {
+                table = reset();
+                code_size = min_code_size + 1;
+                previous = None;
+                continue;
+            }
+            if code == end_code {
+                return decoded;
+            }
+            let entry = match previous {
+                None => table[code as usize].clone(),
+                Some(previous) => {
+                    let previous = table[previous as usize].clone();
+                    let entry = if (code as usize) < table.len() {
+                        table[code as usize].clone()
+                    } else {
+                        [previous.clone(), vec![previous[0]]].concat()
+                    };
+                    if table.len() < LZW_MAX_CODE as usize {
+                        table.push([previous, vec![entry[0]]].concat());
+                        if table.len() == 1 << code_size && code_size < 12 {
+                            code_size += 1;
+                        }
+                    }
+                    entry
+                }
+            };
+            decoded.extend_from_slice(&entry);
+            previous = Some(code);
+        }
+    }
+
+    /// Data of stored deflate blocks in a zlib stream
+    fn zlib_unstored(stream: &[u8]) -> Vec<u8> {
+        let mut data = vec![];
+        let mut rest = &stream[2..];
+        loop {
+            let is_final = rest[0] == 1;
+            let len = u16::from_le_bytes([rest[1], rest[2]]) as usize;
+            assert_eq!(!len as u16, u16::from_le_bytes([rest[3], rest[4]]));
+            data.extend_from_slice(&rest[5..5 + len]);
+            rest = &rest[5 + len..];
+            if is_final {
+                break;
+            }
+        }
+        assert_eq!(rest, adler32(&data).to_be_bytes());
+        data
+    }
+
+    #[test]
+    fn checksums() {
+        assert_eq!(crc32(b"IEND"), 0xae42_6082);
+        assert_eq!(crc32(b"123456789"), 0xcbf4_3926);
+        assert_eq!(adler32(b"Wikipedia"), 0x11e6_0398);
+    }
+
+    #[test]
+    fn zlib_round_trip() {
+        let data: Vec<u8> = (0..200_000).map(|i| (i * 7 % 251) as u8).collect();
+        assert_eq!(zlib_unstored(&zlib_stored(&data)), data);
+        assert_eq!(zlib_unstored(&zlib_stored(&[])), vec![]);
+    }
+
+    #[test]
+    fn lzw_round_trip() {
+        let repetitive: Vec<u8> = (0..50_000).map(|i| (i / 7 % 3) as u8).collect();
+        let noisy: Vec<u8> = (0..50_000u32)
+            .map(|i| (i.wrapping_mul(2_654_435_761) >> 24) as u8)
+            .collect();
+        for (min_code_size, indices) in [
+            (2, vec![0]),
+            (2, vec![1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1, 1]),
+            (2, repetitive),
+            (8, noisy),
+        ] {
+            let decoded = lzw_decode(min_code_size, &lzw_encode(min_code_size, &indices));
+            assert_eq!(decoded, indices);
+        }
+    }
+
+    #[test]
+    fn png_structure() {
+        let grid = Grid::new(3, 2, vec![true, false, true, false, true, false]);
+        let png = encode_png(&grid.to_image());
+
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(&png[16..24], &[0, 0, 0, 3, 0, 0, 0, 2]);
+        assert_eq!(&png[33 + 4..33 + 8], b"PLTE");
+        let idat = 33 + 12 + 6;
+        assert_eq!(&png[idat + 4..idat + 8], b"IDAT");
+        let idat_len = u32::from_be_bytes(png[idat..idat + 4].try_into().unwrap()) as usize;
+        assert_eq!(
+            zlib_unstored(&png[idat + 8..idat + 8 + idat_len]),
+            vec![0, 1, 0, 1, 0, 0, 1, 0]
+        );
+        assert!(png.ends_with(b"IEND\xae\x42\x60\x82"));
+    }
+
+    #[test]
+    fn gif_frames() {
+        let small = Grid::new(1, 1, vec![3]).to_image();
+        let large = Grid::new(3, 3, vec![1; 9]).to_image();
+        let gif = encode_gif(&[small, large], Duration::from_millis(250)).unwrap();
+
+        assert_eq!(&gif[..6], b"GIF89a");
+        assert_eq!(&gif[6..10], &[3, 0, 3, 0]);
+        assert_eq!(gif.last(), Some(&0x3b));
+
+        // first frame: 10 grays in a table of 16 colors, then the 1x1 image centered on 3x3
+        let frame = 13 + 19;
+        assert_eq!(&gif[frame..frame + 3], &[0x21, 0xf9, 0x04]);
+        assert_eq!(&gif[frame + 4..frame + 6], &25u16.to_le_bytes());
+        let descriptor = frame + 8;
+        assert_eq!(gif[descriptor], 0x2c);
+        assert_eq!(gif[descriptor + 9], 0x80 | 3);
+        let data = descriptor + 10 + 16 * 3;
+        assert_eq!(gif[data], 4);
+        let len = gif[data + 1] as usize;
+        assert_eq!(
+            lzw_decode(4, &gif[data + 2..data + 2 + len]),
+            vec![0, 0, 0, 0, 3, 0, 0, 0, 0]
+        );
+
+        // sizes are 16 bit numbers
+        let wide = Grid::new(65536, 1, vec![0; 65536]).to_image();
+        assert_eq!(
+            encode_gif(&[wide], Duration::from_millis(250))
+                .unwrap_err()
+                .to_string(),
+            "GIF frames are at most 65535 pixels wide and high, found 65536x1"
+        );
+    }
+
+    #[test]
+    fn scaling() {
+        let image = Grid::new(2, 1, vec![false, true]).to_image().scaled(2);
+        assert_eq!((image.width(), image.height()), (4, 2));
+        assert_eq!(image.pixels, vec![0, 0, 1, 1, 0, 0, 1, 1]);
+    }
+
+    #[test]
+    fn distinct_hues() {
+        let palette = hue_palette(64);
+        assert_eq!(palette.len(), 64);
+        assert!(palette.windows(2).all(|pair| pair[0] != pair[1]));
+        assert!(!palette.contains(&DARK));
+    }
+}
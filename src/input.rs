@@ -0,0 +1,91 @@
+//! Where puzzle input comes from
+use std::fs;
+use std::io::{self, Read};
+
+/// Source of puzzle input, as given on the command line
+#[derive(Debug, Clone, PartialEq)]
+pub enum InputSource {
+    File(String),
+    Stdin,
+    /// Input given directly, e.g. with `--input-text`
+    Text(String),
+}
+
+impl InputSource {
+    /// `-` stands for stdin, anything else is a file path
+    pub fn from_path(path: &str) -> InputSource {
+        if path == "-" {
+            InputSource::Stdin
+        } else {
+            InputSource::File(path.to_string())
+        }
+    }
+
+    pub fn read(&self) -> io::Result<String> {
+        match self {
+            InputSource::File(path) => fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents)?;
+                Ok(contents)
+            }
+            InputSource::Text(text) => Ok(text.clone()),
+        }
+    }
+
+    /// Short description for messages, e.g. `inputs/day_1_sonar_sweep.txt` or `stdin`
+    pub fn describe(&self) -> String {
+        match self {
+            InputSource::File(path) => path.clone(),
+            InputSource::Stdin => "stdin".to_string(),
+            InputSource::Text(_) => "--input-text".to_string(),
+        }
+    }
+}
+
+/// Input source of a day binary: `[<path> | - | --input-text <text>]`, stdin if nothing is given
+pub fn parse_args(args: &[String]) -> Result<InputSource, String> {
+    match args {
+        [] => Ok(InputSource::Stdin),
+        [flag, text] if flag == "--input-text" => Ok(InputSource::Text(text.clone())),
+        [path] if !path.starts_with("--") => Ok(InputSource::from_path(path)),
+        _ => {
+            Err("Usage: [<path> | - | --input-text <text>], stdin if no path is given".to_string())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|arg| arg.to_string()).collect()
+    }
+
+    #[test]
+    fn sources() {
+        assert_eq!(parse_args(&args(&[])), Ok(InputSource::Stdin));
+        assert_eq!(parse_args(&args(&["-"])), Ok(InputSource::Stdin));
+        assert_eq!(
+            parse_args(&args(&["inputs/day_1_sonar_sweep.txt"])),
+            Ok(InputSource::File(
+                "inputs/day_1_sonar_sweep.txt".to_string()
+            ))
+        );
+        assert_eq!(
+            parse_args(&args(&["--input-text", "1\n2"])),
+            Ok(InputSource::Text("1\n2".to_string()))
+        );
+        assert!(parse_args(&args(&["--input-text"])).is_err());
+        assert!(parse_args(&args(&["a.txt", "b.txt"])).is_err());
+    }
+
+    #[test]
+    fn read_text() {
+        assert_eq!(
+            InputSource::Text("1\n2".to_string()).read().unwrap(),
+            "1\n2"
+        );
+    }
+}
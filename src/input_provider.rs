@@ -0,0 +1,347 @@
+//! Puzzle inputs from the local `inputs/` cache, fetched from the Advent of Code server if missing
+//!
+//! Configured by environment variables:
+//! - `AOC_SESSION`: value of the `session` cookie of a logged in user, required for fetching
+//! - `AOC_BASE_URL`: server to fetch from, `https://adventofcode.com` by default
+//!
+//! The standard library has no TLS, so `https://` URLs are fetched with `curl`,
+//! `http://` URLs (e.g. a local mirror or a test server) with a small built-in HTTP client.
+use crate::registry::Day;
+use std::env;
+use std::error::Error;
+use std::fmt::Display;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::time::Duration;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// Input is not cached and `AOC_SESSION` is not set
+    MissingSession,
+    /// `AOC_SESSION` has whitespace or control characters, which could end the cookie
+    InvalidSession,
+    InvalidUrl(String),
+    Io(io::Error),
+    /// Response other than 200 OK
+    Http {
+        status: u16,
+        body: String,
+    },
+    /// `curl` failed
+    Command(String),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FetchError::MissingSession => {
+                write!(f, "input is not cached and AOC_SESSION is not set")
+            }
+            FetchError::InvalidSession => write!(
+                f,
+                "AOC_SESSION must not contain whitespace or control characters"
+            ),
+            FetchError::InvalidUrl(url) => write!(f, "invalid URL: {}", url),
+            FetchError::Io(err) => write!(f, "{}", err),
+            FetchError::Http { status, body } => {
+                // error pages may be long, the first line is enough
+                let first_line = body.trim().lines().next().unwrap_or("");
+                write!(f, "HTTP status {}: {}", status, first_line)
+            }
+            FetchError::Command(message) => write!(f, "curl failed: {}", message.trim()),
+        }
+    }
+}
+
+impl Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Backend which downloads the input of a day
+pub trait Fetch {
+    fn fetch(&self, day: u8) -> Result<String, FetchError>;
+}
+
+/// Path of the input of a day on the server, e.g. `/2021/day/1/input`
+fn input_path(day: u8) -> String {
+    format!("/2021/day/{}/input", day)
+}
+
+/// Session values go into a header or a config line, a line break would start a new one
+fn check_session(session: &str) -> Result<(), FetchError> {
+    if session.chars().any(|c| c.is_whitespace() || c.is_control()) {
+        return Err(FetchError::InvalidSession);
+    }
+    Ok(())
+}
+
+/// Plain HTTP/1.1 client, for `http://` URLs
+pub struct HttpFetch {
+    host: String,
+    port: u16,
+    /// Path prefix of the base URL, without a trailing `/`
+    prefix: String,
+    session: String,
+}
+
+impl HttpFetch {
+    /// `base_url` like `http://localhost:8080` or `http://mirror/aoc`
+    pub fn new(base_url: &str, session: &str) -> Result<HttpFetch, FetchError> {
+        let invalid_url = || FetchError::InvalidUrl(base_url.to_string());
+        let rest = base_url.strip_prefix("http://").ok_or_else(invalid_url)?;
+        let (authority, prefix) = match rest.find('/') {
+            Some(i) => (&rest[..i], rest[i..].trim_end_matches('/')),
+            None => (rest, ""),
+        };
+        let (host, port) = match authority.split_once(':') {
+            Some((host, port)) => (host, port.parse::<u16>().map_err(|_| invalid_url())?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid_url());
+        }
+        check_session(session)?;
+
+        Ok(HttpFetch {
+            host: host.to_string(),
+            port,
+            prefix: prefix.to_string(),
+            session: session.to_string(),
+        })
+    }
+}
+
+impl Fetch for HttpFetch {
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        let mut stream = TcpStream::connect((self.host.as_str(), self.port))?;
+        stream.set_read_timeout(Some(Duration::from_secs(30)))?;
+        write!(
+            stream,
+            "GET {}{} HTTP/1.1\r\nHost: {}\r\nCookie: session={}\r\nUser-Agent: advent_of_code_2021 input provider\r\nConnection: close\r\n\r\n",
+            self.prefix,
+            input_path(day),
+            self.host,
+            self.session
+        )?;
+
+        let mut response = vec![];
+        stream.read_to_end(&mut response)?;
+        parse_response(&response)
+    }
+}
+
+/// Body of a 200 OK response, plain or with chunked transfer encoding
+fn parse_response(response: &[u8]) -> Result<String, FetchError> {
+    let invalid_response = || {
+        FetchError::Io(io::Error::new(
+            io::ErrorKind::InvalidData,
+            "invalid HTTP response",
+        ))
+    };
+
+    let header_end = response
+        .windows(4)
+        .position(|window| window == b"\r\n\r\n")
+        .ok_or_else(invalid_response)?;
+    let head = String::from_utf8_lossy(&response[..header_end]);
+    let body = &response[header_end + 4..];
+
+    let mut lines = head.lines();
+    let status = lines
+        .next()
+        .and_then(|status_line| status_line.split(' ').nth(1))
+        .and_then(|status| status.parse::<u16>().ok())
+        .ok_or_else(invalid_response)?;
+    let chunked = lines.any(|header| {
+        let header = header.to_ascii_lowercase();
+        header.starts_with("transfer-encoding:") && header.contains("chunked")
+    });
+
+    let body = if chunked {
+        decode_chunked(body).ok_or_else(invalid_response)?
+    } else {
+        body.to_vec()
+    };
+    let body = String::from_utf8(body).map_err(|_| invalid_response())?;
+
+    if status == 200 {
+        Ok(body)
+    } else {
+        Err(FetchError::Http { status, body })
+    }
+}
+
+/// Body sent as chunks: hexadecimal size, CRLF, data, CRLF, ..., ending with a chunk of size 0
+fn decode_chunked(mut body: &[u8]) -> Option<Vec<u8>> {
+    let mut decoded = vec![];
+    loop {
+        let line_end = body.windows(2).position(|window| window == b"\r\n")?;
+        let size_line = std::str::from_utf8(&body[..line_end]).ok()?;
+        // chunk extensions follow a `;`
+        let size = usize::from_str_radix(size_line.split(';').next()?.trim(), 16).ok()?;
+        body = &body[line_end + 2..];
+        if size == 0 {
+            return Some(decoded);
+        }
+        decoded.extend_from_slice(body.get(..size)?);
+        body = body.get(size + 2..)?;
+    }
+}
+
+/// Fetches with the `curl` command, for `https://` URLs
+pub struct CurlFetch {
+    base_url: String,
+    session: String,
+}
+
+impl CurlFetch {
+    pub fn new(base_url: &str, session: &str) -> Result<CurlFetch, FetchError> {
+        check_session(session)?;
+        Ok(CurlFetch {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session: session.to_string(),
+        })
+    }
+}
+
+impl Fetch for CurlFetch {
+    fn fetch(&self, day: u8) -> Result<String, FetchError> {
+        // the session goes to curl's stdin as a config file, arguments are visible to other users
+        let mut child = Command::new("curl")
+            .args(["--silent", "--show-error", "--fail", "--config", "-"])
+            .arg(format!("{}{}", self.base_url, input_path(day)))
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?;
+        if let Some(mut stdin) = child.stdin.take() {
+            let session = self.session.replace('\\', "\\\\").replace('"', "\\\"");
+            writeln!(stdin, "cookie = \"session={}\"", session)?;
+        }
+        let output = child.wait_with_output()?;
+        if output.status.success() {
+            String::from_utf8(output.stdout)
+                .map_err(|_| FetchError::Command("response is not UTF-8".to_string()))
+        } else {
+            Err(FetchError::Command(
+                String::from_utf8_lossy(&output.stderr).to_string(),
+            ))
+        }
+    }
+}
+
+/// Backend for the base URL: built-in HTTP client or `curl`
+pub fn fetcher_for(base_url: &str, session: &str) -> Result<Box<dyn Fetch>, FetchError> {
+    if base_url.starts_with("http://") {
+        Ok(Box::new(HttpFetch::new(base_url, session)?))
+    } else if base_url.starts_with("https://") {
+        Ok(Box::new(CurlFetch::new(base_url, session)?))
+    } else {
+        Err(FetchError::InvalidUrl(base_url.to_string()))
+    }
+}
+
+/// Resolves inputs from a cache directory, fetching and saving missing ones
+pub struct InputProvider {
+    cache_dir: PathBuf,
+    /// `None` if inputs can only come from the cache
+    fetcher: Option<Box<dyn Fetch>>,
+}
+
+impl InputProvider {
+    pub fn new<P: AsRef<Path>>(cache_dir: P, fetcher: Option<Box<dyn Fetch>>) -> InputProvider {
+        InputProvider {
+            cache_dir: cache_dir.as_ref().to_path_buf(),
+            fetcher,
+        }
+    }
+
+    /// Cache in `inputs/`, fetching configured by `AOC_SESSION` and `AOC_BASE_URL`
+    pub fn from_env() -> Result<InputProvider, FetchError> {
+        let fetcher = match env::var("AOC_SESSION") {
+            Ok(session) if !session.is_empty() => {
+                let base_url =
+                    env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string());
+                Some(fetcher_for(&base_url, &session)?)
+            }
+            _ => None,
+        };
+        Ok(InputProvider::new("inputs", fetcher))
+    }
+
+    pub fn cache_path(&self, day: &Day) -> PathBuf {
+        self.cache_dir.join(format!("{}.txt", day.name()))
+    }
+
+    pub fn is_cached(&self, day: &Day) -> bool {
+        self.cache_path(day).exists()
+    }
+
+    /// Input of the day, from the cache if possible. Fetched inputs are saved to the cache.
+    pub fn input(&self, day: &Day) -> Result<String, FetchError> {
+        let path = self.cache_path(day);
+        if path.exists() {
+            return Ok(fs::read_to_string(path)?);
+        }
+
+        let fetcher = self.fetcher.as_ref().ok_or(FetchError::MissingSession)?;
+        let input = fetcher.fetch(day.number)?;
+        fs::create_dir_all(&self.cache_dir)?;
+        fs::write(&path, &input)?;
+        Ok(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn base_url() {
+        let fetch = HttpFetch::new("http://localhost:8080/aoc/", "abc").unwrap();
+        assert_eq!(fetch.host, "localhost");
+        assert_eq!(fetch.port, 8080);
+        assert_eq!(fetch.prefix, "/aoc");
+        assert_eq!(HttpFetch::new("http://mirror", "abc").unwrap().port, 80);
+        assert!(HttpFetch::new("https://adventofcode.com", "abc").is_err());
+        assert!(HttpFetch::new("http://localhost:port", "abc").is_err());
+        assert!(fetcher_for("ftp://adventofcode.com", "abc").is_err());
+        assert!(matches!(
+            HttpFetch::new("http://mirror", "abc\r\nX-Injected: 1"),
+            Err(FetchError::InvalidSession)
+        ));
+        assert!(matches!(
+            fetcher_for("https://adventofcode.com", "abc\noutput = /tmp/x"),
+            Err(FetchError::InvalidSession)
+        ));
+    }
+
+    #[test]
+    fn responses() {
+        assert_eq!(
+            parse_response(b"HTTP/1.1 200 OK\r\nContent-Length: 6\r\n\r\n1\n2\n3\n").unwrap(),
+            "1\n2\n3\n"
+        );
+        assert_eq!(
+            parse_response(
+                b"HTTP/1.1 200 OK\r\nTransfer-Encoding: chunked\r\n\r\n4\r\n1\n2\n\r\n2\r\n3\n\r\n0\r\n\r\n"
+            )
+            .unwrap(),
+            "1\n2\n3\n"
+        );
+        assert!(matches!(
+            parse_response(b"HTTP/1.1 404 Not Found\r\n\r\nnot found"),
+            Err(FetchError::Http { status: 404, .. })
+        ));
+        assert!(parse_response(b"garbage").is_err());
+    }
+}
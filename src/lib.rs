@@ -0,0 +1,86 @@
+//! Solutions of Advent of Code 2021
+//! https://adventofcode.com/2021
+use crate::error::ParseError;
+use crate::output::Format;
+use crate::runner::Solver;
+use std::env;
+use std::fmt::Display;
+use std::process;
+
+pub mod answers;
+pub mod config;
+pub mod days;
+pub mod error;
+pub mod generate;
+pub mod grid;
+pub mod image;
+pub mod input;
+pub mod input_provider;
+pub mod output;
+pub mod profile;
+pub mod registry;
+pub mod runner;
+pub mod sonar;
+pub mod visualize;
+
+/// A solution to one day of Advent of Code.
+///
+/// Input is parsed once and shared by both parts.
+pub trait Solution {
+    /// Day of Advent, 1 to 25
+    const DAY: u8;
+
+    type Input;
+    type Answer1: Display;
+    type Answer2: Display;
+
+    fn parse(&self, input: &str) -> Result<Self::Input, ParseError>;
+    fn part_1(&self, input: &Self::Input) -> Self::Answer1;
+    fn part_2(&self, input: &Self::Input) -> Self::Answer2;
+}
+
+/// Entry point shared by the day binaries.
+/// Reads input from the file given as the first command line argument,
+/// from stdin (no argument or `-`) or from `--input-text <text>`, and prints answers to both parts.
+/// `--format json` prints the answers as JSON records, see `output::json_record`.
+pub fn run<S: Solution + Sync>(solution: S) {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    let (format, source) = match output::take_format(&mut args)
+        .and_then(|format| Ok((format, input::parse_args(&args)?)))
+    {
+        Ok(options) => options,
+        Err(message) => {
+            eprintln!("{}", message);
+            process::exit(2);
+        }
+    };
+    let contents = match source.read() {
+        Ok(contents) => contents,
+        Err(err) => {
+            eprintln!("Cannot read {}: {}", source.describe(), err);
+            process::exit(1);
+        }
+    };
+    let result = match solution.solve(&contents, &[1, 2]) {
+        Ok(result) => result,
+        Err(err) => {
+            eprintln!("{}", err);
+            process::exit(1);
+        }
+    };
+    for part in result.parts {
+        match format {
+            Format::Text => print_answer(part.part, &part.answer),
+            Format::Json => println!("{}", output::json_record(S::DAY, &part)),
+        }
+    }
+}
+
+fn print_answer(part: u8, answer: &str) {
+    if answer.contains('\n') {
+        // multi-line answers (e.g. letters drawn with '#') start on their own line
+        println!("[part {}]\n{}", part, answer);
+    } else {
+        println!("[part {}] {}", part, answer);
+    }
+}
@@ -0,0 +1,114 @@
+//! Printing answers for people or for other programs
+use crate::runner::PartResult;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Format {
+    Text,
+    /// One JSON object per answer and line, see `json_record`
+    Json,
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(Format::Text),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("Unknown format: {} (expected text or json)", s)),
+        }
+    }
+}
+
+/// Answer which may not fit in its integer type with changed puzzle parameters or large inputs,
+/// printed as `overflow` then
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Count<T = u64>(pub Option<T>);
+
+impl<T: fmt::Display> fmt::Display for Count<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.0 {
+            Some(count) => write!(f, "{}", count),
+            None => write!(f, "overflow"),
+        }
+    }
+}
+
+/// Removes `--format <text|json>` from the arguments and returns the format, text by default
+pub fn take_format(args: &mut Vec<String>) -> Result<Format, String> {
+    match args.iter().position(|arg| arg == "--format") {
+        Some(i) if i + 1 < args.len() => {
+            let format = args[i + 1].parse()?;
+            args.drain(i..i + 2);
+            Ok(format)
+        }
+        Some(_) => Err("--format requires text or json".to_string()),
+        None => Ok(Format::Text),
+    }
+}
+
+/// Answer to one part as a single line of JSON, e.g.
+/// `{"day":1,"part":1,"answer":"1559","duration_ns":2058}`
+pub fn json_record(day: u8, result: &PartResult) -> String {
+    format!(
+        "{{\"day\":{},\"part\":{},\"answer\":{},\"duration_ns\":{}}}",
+        day,
+        result.part,
+        json_string(&result.answer),
+        result.duration.as_nanos()
+    )
+}
+
+/// Quoted JSON string, with special characters escaped
+fn json_string(s: &str) -> String {
+    let mut quoted = String::with_capacity(s.len() + 2);
+    quoted.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn record() {
+        let result = PartResult {
+            part: 2,
+            answer: "#..#\n\"a\"\\".to_string(),
+            duration: Duration::from_micros(3),
+        };
+        assert_eq!(
+            json_record(13, &result),
+            r##"{"day":13,"part":2,"answer":"#..#\n\"a\"\\","duration_ns":3000}"##
+        );
+    }
+
+    #[test]
+    fn format_argument() {
+        let mut args = vec![
+            "a.txt".to_string(),
+            "--format".to_string(),
+            "json".to_string(),
+        ];
+        assert_eq!(take_format(&mut args), Ok(Format::Json));
+        assert_eq!(args, vec!["a.txt".to_string()]);
+        assert_eq!(take_format(&mut args), Ok(Format::Text));
+        assert!(take_format(&mut vec!["--format".to_string()]).is_err());
+        assert!(take_format(&mut vec!["--format".to_string(), "xml".to_string()]).is_err());
+    }
+}
@@ -0,0 +1,223 @@
+//! Memory profiling: heap usage from a counting global allocator, stack usage from painting
+//! the stack with a pattern and looking for the deepest byte which changed.
+//!
+//! The allocator only counts if a binary installs it, as the `profile` binary does:
+//! `#[global_allocator] static ALLOCATOR: CountingAllocator = CountingAllocator;`
+//! Without it, heap usage is reported as 0.
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::fmt;
+use std::hint::black_box;
+use std::panic;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+/// Stack size of `thread`, most of which is painted by `measure`
+pub const STACK_SIZE: usize = 64 << 20;
+
+/// Stack usage below this (the frames of `measure` itself) is not measured
+const UNPAINTED: usize = 4 << 10;
+
+/// Painted stack below `measure`, leaving room for the frames above it and the guard page
+const PAINTED: usize = STACK_SIZE - (4 << 20);
+
+const PATTERN: u64 = 0xa5a5_a5a5_a5a5_a5a5;
+
+/// Bytes currently allocated
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+/// Most bytes allocated at once since the last `measure` started
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+/// Bytes allocated in total, including those freed again
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+
+thread_local! {
+    static ON_PROFILING_THREAD: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Global allocator which counts allocations of the system allocator
+pub struct CountingAllocator;
+
+fn record_allocation(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    ALLOCATED.fetch_add(size, Ordering::Relaxed);
+    let current = CURRENT.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(current, Ordering::Relaxed);
+}
+
+// SAFETY: all allocations are done by `System`, the counters only observe them
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_allocation(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            CURRENT.fetch_sub(layout.size(), Ordering::Relaxed);
+            record_allocation(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Memory used by a function, see `measure`
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Usage {
+    /// Most bytes allocated at once, on top of what was allocated before
+    pub peak_heap: usize,
+    pub allocations: usize,
+    /// Bytes allocated in total, including those freed again
+    pub allocated: usize,
+    /// Deepest stack, from the caller of `measure`. Usage below 4 KiB shows as about 4 KiB.
+    pub peak_stack: usize,
+}
+
+/// Size in bytes, e.g. `512 B` or `1.5 MiB`
+pub struct Bytes(pub usize);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let units = ["B", "KiB", "MiB", "GiB"];
+        let mut size = self.0 as f64;
+        let mut unit = 0;
+        while size >= 1024.0 && unit + 1 < units.len() {
+            size /= 1024.0;
+            unit += 1;
+        }
+        let text = if unit == 0 {
+            format!("{} B", self.0)
+        } else {
+            format!("{:.1} {}", size, units[unit])
+        };
+        f.pad(&text)
+    }
+}
+
+/// Address of a local variable, close to the stack pointer of the calling frame
+#[inline(always)]
+fn stack_address() -> usize {
+    let marker = 0u8;
+    black_box(&marker) as *const u8 as usize
+}
+
+/// Fills `PAINTED` bytes of the stack below the current frame with `PATTERN`,
+/// returns the lowest and highest painted address
+#[inline(never)]
+fn paint_stack() -> (usize, usize) {
+    let top = (stack_address() - UNPAINTED) & !7;
+    let bottom = top - PAINTED;
+    for address in (bottom..top).step_by(8) {
+        // SAFETY: the stack of `thread` reaches at least `STACK_SIZE` below its first frame,
+        // and nothing lives below the current frame
+        unsafe { (address as *mut u64).write_volatile(PATTERN) };
+    }
+    (bottom, top)
+}
+
+/// Lowest address between `bottom` and `top` which no longer holds `PATTERN`
+#[inline(never)]
+fn lowest_changed(bottom: usize, top: usize) -> Option<usize> {
+    (bottom..top)
+        .step_by(8)
+        // SAFETY: the range was painted by `paint_stack`, its memory is part of this thread's stack
+        .find(|&address| unsafe { (address as *const u64).read_volatile() } != PATTERN)
+}
+
+/// Runs `f` on a thread with a stack of `STACK_SIZE`, which `measure` requires
+pub fn thread<T: Send + 'static>(f: impl FnOnce() -> T + Send + 'static) -> T {
+    let handle = thread::Builder::new()
+        .name("profile".to_string())
+        .stack_size(STACK_SIZE)
+        .spawn(|| {
+            ON_PROFILING_THREAD.with(|on| on.set(true));
+            f()
+        })
+        .expect("Cannot start a profiling thread");
+    match handle.join() {
+        Ok(result) => result,
+        Err(payload) => panic::resume_unwind(payload),
+    }
+}
+
+/// Runs `f` and measures the heap and stack memory it uses.
+/// Must be called on the thread of `thread`, close to its start, and with no other threads
+/// allocating, as the allocator counts allocations of all threads.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    assert!(
+        ON_PROFILING_THREAD.with(Cell::get),
+        "profile::measure must run on profile::thread"
+    );
+    let base = stack_address();
+    let (bottom, top) = paint_stack();
+
+    let current = CURRENT.load(Ordering::Relaxed);
+    PEAK.store(current, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let allocated = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        peak_heap: PEAK.load(Ordering::Relaxed).saturating_sub(current),
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        allocated: ALLOCATED.load(Ordering::Relaxed) - allocated,
+        peak_stack: base - lowest_changed(bottom, top).unwrap_or(top),
+    };
+    (result, usage)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[inline(never)]
+    fn sum_of_large_array() -> u64 {
+        let numbers = black_box([1u64; 32 << 10]);
+        numbers.iter().sum()
+    }
+
+    #[test]
+    fn measure_heap_and_stack() {
+        let (_, usage) = thread(|| {
+            measure(|| {
+                let numbers = black_box(vec![1u8; 1 << 20]);
+                numbers.len()
+            })
+        });
+        assert!(usage.peak_heap >= 1 << 20);
+        assert!(usage.allocations >= 1);
+        assert!(usage.peak_stack < 64 << 10);
+
+        let (sum, usage) = thread(|| measure(sum_of_large_array));
+        assert_eq!(sum, 32 << 10);
+        assert!(usage.peak_stack >= 256 << 10);
+    }
+
+    #[test]
+    fn bytes() {
+        assert_eq!(Bytes(512).to_string(), "512 B");
+        assert_eq!(Bytes(1536).to_string(), "1.5 KiB");
+        assert_eq!(format!("{:>8}", Bytes(3 << 20)), " 3.0 MiB");
+    }
+}
@@ -0,0 +1,312 @@
+//! List of all implemented days
+use crate::days::*;
+use crate::runner::Solver;
+
+/// A day of Advent of Code with its solution and what is known about it
+pub struct Day {
+    pub number: u8,
+    /// Title as used in file names, e.g. `sonar_sweep`
+    pub title: &'static str,
+    /// Title of the puzzle page, e.g. `Sonar Sweep`
+    pub puzzle_title: &'static str,
+    pub solver: &'static dyn Solver,
+    /// Answers to both parts on the test input, None if the day has no test input
+    pub example_answers: Option<[&'static str; 2]>,
+    /// Parts which take too long on the real input for a regular test or benchmark run
+    pub slow_parts: &'static [u8],
+}
+
+impl Day {
+    /// Name of the binary and of the input files, e.g. `day_1_sonar_sweep`
+    pub fn name(&self) -> String {
+        format!("day_{}_{}", self.number, self.title)
+    }
+
+    pub fn url(&self) -> String {
+        format!("https://adventofcode.com/2021/day/{}", self.number)
+    }
+
+    pub fn input_path(&self) -> String {
+        format!("inputs/{}.txt", self.name())
+    }
+
+    pub fn test_input_path(&self) -> String {
+        format!("test_inputs/{}.txt", self.name())
+    }
+
+    /// Expected answer to a part on the test input
+    pub fn example_answer(&self, part: u8) -> Option<&'static str> {
+        let answers = self.example_answers?;
+        answers.get(usize::from(part).checked_sub(1)?).copied()
+    }
+
+    pub fn is_slow(&self, part: u8) -> bool {
+        self.slow_parts.contains(&part)
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        title: "sonar_sweep",
+        puzzle_title: "Sonar Sweep",
+        solver: &day_1_sonar_sweep::SonarSweep,
+        example_answers: Some(["7", "5"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 2,
+        title: "dive",
+        puzzle_title: "Dive!",
+        solver: &day_2_dive::Dive,
+        example_answers: Some(["150", "900"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 3,
+        title: "binary_diagnostic",
+        puzzle_title: "Binary Diagnostic",
+        solver: &day_3_binary_diagnostic::BinaryDiagnostic,
+        example_answers: Some(["198", "230"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 4,
+        title: "giant_squid",
+        puzzle_title: "Giant Squid",
+        solver: &day_4_giant_squid::GiantSquid,
+        example_answers: Some(["4512", "1924"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 5,
+        title: "hydrothermal_venture",
+        puzzle_title: "Hydrothermal Venture",
+        solver: &day_5_hydrothermal_venture::HydrothermalVenture,
+        example_answers: Some(["5", "12"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 6,
+        title: "lanternfish",
+        puzzle_title: "Lanternfish",
+        solver: &day_6_lanternfish::Lanternfish::PUZZLE,
+        example_answers: Some(["5934", "26984457539"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 7,
+        title: "treachery_of_whales",
+        puzzle_title: "The Treachery of Whales",
+        solver: &day_7_treachery_of_whales::TreacheryOfWhales,
+        example_answers: Some(["37", "168"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 8,
+        title: "seven_segment_search",
+        puzzle_title: "Seven Segment Search",
+        solver: &day_8_seven_segment_search::SevenSegmentSearch,
+        example_answers: Some(["26", "61229"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 9,
+        title: "smoke_basin",
+        puzzle_title: "Smoke Basin",
+        solver: &day_9_smoke_basin::SmokeBasin,
+        example_answers: Some(["15", "1134"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 10,
+        title: "syntax_scoring",
+        puzzle_title: "Syntax Scoring",
+        solver: &day_10_syntax_scoring::SyntaxScoring,
+        example_answers: Some(["26397", "288957"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 11,
+        title: "dumbo_octopus",
+        puzzle_title: "Dumbo Octopus",
+        solver: &day_11_dumbo_octopus::DumboOctopus::PUZZLE,
+        example_answers: Some(["1656", "195"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 12,
+        title: "passage_pathing",
+        puzzle_title: "Passage Pathing",
+        solver: &day_12_passage_pathing::PassagePathing,
+        example_answers: Some(["10", "36"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 13,
+        title: "transparent_origami",
+        puzzle_title: "Transparent Origami",
+        solver: &day_13_transparent_origami::TransparentOrigami,
+        example_answers: Some(["17", "#####\n#...#\n#...#\n#...#\n#####"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 14,
+        title: "extended_polymerization",
+        puzzle_title: "Extended Polymerization",
+        solver: &day_14_extended_polymerization::ExtendedPolymerization::PUZZLE,
+        example_answers: Some(["1588", "2188189693529"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 15,
+        title: "chiton",
+        puzzle_title: "Chiton",
+        solver: &day_15_chiton::Chiton::PUZZLE,
+        example_answers: Some(["40", "315"]),
+        slow_parts: &[2],
+    },
+    Day {
+        number: 16,
+        title: "packet_decoder",
+        puzzle_title: "Packet Decoder",
+        solver: &day_16_packet_decoder::PacketDecoder,
+        example_answers: None,
+        slow_parts: &[],
+    },
+    Day {
+        number: 17,
+        title: "trick_shot",
+        puzzle_title: "Trick Shot",
+        solver: &day_17_trick_shot::TrickShot,
+        example_answers: Some(["45", "112"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 18,
+        title: "snailfish",
+        puzzle_title: "Snailfish",
+        solver: &day_18_snailfish::Snailfish,
+        example_answers: Some(["4140", "3993"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 19,
+        title: "beacon_scanner",
+        puzzle_title: "Beacon Scanner",
+        solver: &day_19_beacon_scanner::BeaconScanner::PUZZLE,
+        example_answers: Some(["79", "3621"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 20,
+        title: "trench_map",
+        puzzle_title: "Trench Map",
+        solver: &day_20_trench_map::TrenchMap::PUZZLE,
+        example_answers: Some(["35", "3351"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 21,
+        title: "dirac_dice",
+        puzzle_title: "Dirac Dice",
+        solver: &day_21_dirac_dice::DiracDice::PUZZLE,
+        example_answers: Some(["739785", "444356092776315"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 22,
+        title: "reactor_reboot",
+        puzzle_title: "Reactor Reboot",
+        solver: &day_22_reactor_reboot::ReactorReboot::PUZZLE,
+        example_answers: Some(["474140", "2758514936282235"]),
+        slow_parts: &[2],
+    },
+    Day {
+        number: 23,
+        title: "amphipod",
+        puzzle_title: "Amphipod",
+        solver: &day_23_amphipod::Amphipods,
+        example_answers: Some(["12521", "44169"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 24,
+        title: "arithmetic_logic_unit",
+        puzzle_title: "Arithmetic Logic Unit",
+        solver: &day_24_arithmetic_logic_unit::ArithmeticLogicUnit,
+        example_answers: Some(["97", "31"]),
+        slow_parts: &[],
+    },
+    Day {
+        number: 25,
+        title: "sea_cucumber",
+        puzzle_title: "Sea Cucumber",
+        solver: &day_25_sea_cucumber::SeaCucumbers,
+        example_answers: Some(["58", "Merry Christmas!"]),
+        slow_parts: &[],
+    },
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Parses day selection: "all", a single day ("15") or a range ("1..=10", "1..11")
+pub fn parse_days(s: &str) -> Result<Vec<&'static Day>, String> {
+    if s == "all" {
+        return Ok(DAYS.iter().collect());
+    }
+
+    let (first, last) = if let Some((first, last)) = s.split_once("..=") {
+        (first, last.parse::<u8>())
+    } else if let Some((first, end)) = s.split_once("..") {
+        (first, end.parse::<u8>().map(|end| end.saturating_sub(1)))
+    } else {
+        (s, s.parse::<u8>())
+    };
+    let first = first
+        .parse::<u8>()
+        .map_err(|_| format!("Invalid day: {}", s))?;
+    let last = last.map_err(|_| format!("Invalid day: {}", s))?;
+
+    if first == last {
+        return find(first)
+            .map(|day| vec![day])
+            .ok_or(format!("Day {} is not implemented", first));
+    }
+
+    let days: Vec<&'static Day> = DAYS
+        .iter()
+        .filter(|day| first <= day.number && day.number <= last)
+        .collect();
+    if days.is_empty() {
+        Err(format!("No implemented days in {}", s))
+    } else {
+        Ok(days)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_selections() {
+        let numbers = |s: &str| -> Vec<u8> {
+            parse_days(s)
+                .unwrap()
+                .into_iter()
+                .map(|day| day.number)
+                .collect()
+        };
+        let error = |s: &str| parse_days(s).err().unwrap();
+        assert_eq!(numbers("all").len(), DAYS.len());
+        assert_eq!(numbers("15"), [15]);
+        assert_eq!(numbers("1..=3"), [1, 2, 3]);
+        assert_eq!(numbers("1..3"), [1, 2]);
+        assert_eq!(error("26"), "Day 26 is not implemented");
+        assert_eq!(error("26..=30"), "No implemented days in 26..=30");
+        assert_eq!(error("x..=3"), "Invalid day: x..=3");
+    }
+}
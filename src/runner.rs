@@ -0,0 +1,228 @@
+//! Running solutions without knowing their concrete types
+use crate::error::ParseError;
+use crate::profile::{self, Usage};
+use crate::Solution;
+use std::panic;
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+use std::time::Instant;
+
+/// Answer to one part of a puzzle, rendered to a string
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: String,
+    pub duration: Duration,
+}
+
+/// Answers to the requested parts of a puzzle
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayResult {
+    pub parse_duration: Duration,
+    pub parts: Vec<PartResult>,
+}
+
+/// Memory used to parse the input and to solve each requested part
+#[derive(Debug, Clone, PartialEq)]
+pub struct DayUsage {
+    pub parse: Usage,
+    /// Part number and its usage
+    pub parts: Vec<(u8, Usage)>,
+}
+
+/// Object safe counterpart of `Solution`.
+/// Implemented for every `Solution`, so days can be stored side by side in a registry.
+pub trait Solver: Sync {
+    /// Parses the input and solves the given parts (1 and/or 2), timing each step
+    fn solve(&self, input: &str, parts: &[u8]) -> Result<DayResult, ParseError>;
+
+    /// Like `solve`, but measures the memory of each step instead of its time.
+    /// Must run on `profile::thread`, see `profile::measure`.
+    fn profile(&self, input: &str, parts: &[u8]) -> Result<DayUsage, ParseError>;
+}
+
+impl<S: Solution + Sync> Solver for S {
+    fn solve(&self, input: &str, parts: &[u8]) -> Result<DayResult, ParseError> {
+        let start = Instant::now();
+        let parsed = self.parse(input).map_err(|err| err.for_day(S::DAY))?;
+        let parse_duration = start.elapsed();
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let start = Instant::now();
+                let answer = match part {
+                    1 => self.part_1(&parsed).to_string(),
+                    2 => self.part_2(&parsed).to_string(),
+                    _ => panic!("There are only 2 parts!"),
+                };
+                PartResult {
+                    part,
+                    answer,
+                    duration: start.elapsed(),
+                }
+            })
+            .collect();
+
+        Ok(DayResult {
+            parse_duration,
+            parts,
+        })
+    }
+
+    fn profile(&self, input: &str, parts: &[u8]) -> Result<DayUsage, ParseError> {
+        let (parsed, parse) = profile::measure(|| self.parse(input));
+        let parsed = parsed.map_err(|err| err.for_day(S::DAY))?;
+
+        let parts = parts
+            .iter()
+            .map(|&part| {
+                let (_, usage) = profile::measure(|| match part {
+                    1 => drop(self.part_1(&parsed)),
+                    2 => drop(self.part_2(&parsed)),
+                    _ => panic!("There are only 2 parts!"),
+                });
+                (part, usage)
+            })
+            .collect();
+
+        Ok(DayUsage { parse, parts })
+    }
+}
+
+/// A day to solve with `run_parallel`
+pub struct Job {
+    pub solver: &'static dyn Solver,
+    pub input: String,
+    pub parts: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Outcome {
+    Solved(DayResult),
+    /// Invalid input or a panic
+    Failed(String),
+    TimedOut,
+}
+
+/// Solves the jobs on up to `threads` threads at once, returns their outcomes in the same order.
+/// Jobs running longer than `timeout` are reported as timed out. Their threads cannot be stopped,
+/// they keep running in the background, but no longer count towards `threads`.
+pub fn run_parallel(jobs: Vec<Job>, threads: usize, timeout: Duration) -> Vec<Outcome> {
+    let job_count = jobs.len();
+    let mut outcomes: Vec<Option<Outcome>> = vec![None; job_count];
+    let mut pending = jobs.into_iter().enumerate();
+    // job index, start
+    let mut running: Vec<(usize, Instant)> = vec![];
+    let (sender, receiver) = mpsc::channel();
+
+    while outcomes.iter().any(Option::is_none) {
+        while running.len() < threads.max(1) {
+            let Some((index, job)) = pending.next() else {
+                break;
+            };
+            let sender = sender.clone();
+            thread::spawn(move || {
+                let solve = || job.solver.solve(&job.input, &job.parts);
+                let outcome = match panic::catch_unwind(panic::AssertUnwindSafe(solve)) {
+                    Ok(Ok(result)) => Outcome::Solved(result),
+                    Ok(Err(err)) => Outcome::Failed(err.to_string()),
+                    Err(_) => Outcome::Failed("panicked".to_string()),
+                };
+                // the receiver is gone if all other jobs finished after this one timed out
+                let _ = sender.send((index, outcome));
+            });
+            running.push((index, Instant::now()));
+        }
+
+        let first_deadline = running.iter().map(|&(_, start)| start + timeout).min();
+        let wait = first_deadline.map_or(Duration::ZERO, |deadline| {
+            deadline.saturating_duration_since(Instant::now())
+        });
+        match receiver.recv_timeout(wait) {
+            Ok((index, outcome)) => {
+                // results of timed out jobs come too late
+                if outcomes[index].is_none() {
+                    outcomes[index] = Some(outcome);
+                    running.retain(|&(running_index, _)| running_index != index);
+                }
+            }
+            Err(_) => {
+                let now = Instant::now();
+                for &(index, _) in running.iter().filter(|&&(_, start)| now >= start + timeout) {
+                    outcomes[index] = Some(Outcome::TimedOut);
+                }
+                running.retain(|&(_, start)| now < start + timeout);
+            }
+        }
+    }
+
+    outcomes.into_iter().map(Option::unwrap).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day_1_sonar_sweep::SonarSweep;
+    use crate::days::day_6_lanternfish::Lanternfish;
+
+    /// Never finishes part 1, in a test thread that is left running
+    struct Stuck;
+
+    impl Solution for Stuck {
+        const DAY: u8 = 0;
+
+        type Input = ();
+        type Answer1 = u8;
+        type Answer2 = u8;
+
+        fn parse(&self, _input: &str) -> Result<Self::Input, ParseError> {
+            Ok(())
+        }
+
+        fn part_1(&self, _input: &Self::Input) -> Self::Answer1 {
+            loop {
+                thread::park();
+            }
+        }
+
+        fn part_2(&self, _input: &Self::Input) -> Self::Answer2 {
+            2
+        }
+    }
+
+    fn job(solver: &'static dyn Solver, input: &str, parts: &[u8]) -> Job {
+        Job {
+            solver,
+            input: input.to_string(),
+            parts: parts.to_vec(),
+        }
+    }
+
+    #[test]
+    fn outcomes_in_order() {
+        let jobs = vec![
+            job(&Stuck, "", &[1]),
+            job(&SonarSweep, "1\n2\n3\n2\n", &[1, 2]),
+            job(&Lanternfish::PUZZLE, "9", &[1]),
+            job(&Stuck, "", &[2]),
+            job(&Stuck, "", &[1, 2]),
+        ];
+        let outcomes = run_parallel(jobs, 2, Duration::from_millis(200));
+
+        assert_eq!(outcomes[0], Outcome::TimedOut);
+        let Outcome::Solved(result) = &outcomes[1] else {
+            panic!("Expected answers, got {:?}", outcomes[1]);
+        };
+        let answers: Vec<&str> = result
+            .parts
+            .iter()
+            .map(|part| part.answer.as_str())
+            .collect();
+        assert_eq!(answers, ["2", "1"]);
+        assert!(matches!(&outcomes[2], Outcome::Failed(err) if err.contains("timer")));
+        assert!(matches!(&outcomes[3], Outcome::Solved(result) if result.parts[0].answer == "2"));
+        assert_eq!(outcomes[4], Outcome::TimedOut);
+    }
+}
@@ -0,0 +1,318 @@
+//! Tolerant reading of sonar depth logs (day 1). Blank and malformed lines and outliers are
+//! reported as anomalies instead of failing, what remains is a cleaned series of depths.
+//!
+//! Outliers are found with the median absolute deviation (MAD) of a trailing window of depths:
+//! a depth is an outlier if its modified z-score, `0.6745 * |depth - median| / MAD`,
+//! is above a threshold. Outliers stay in the window, so that a lasting change of depth
+//! is accepted once it fills more than half of the window.
+use crate::days::day_1_sonar_sweep::{SweepAnalytics, SweepReport};
+use std::collections::VecDeque;
+use std::fmt;
+
+/// Depths in the window of `OutlierFilter`. Depths drift, a long window keeps steep stretches
+/// from looking like outliers.
+pub const DEFAULT_WINDOW: usize = 41;
+
+/// Modified z-score above which a depth is an outlier. Higher than the usual 3.5,
+/// as depths drift within the window.
+pub const DEFAULT_THRESHOLD: f64 = 8.0;
+
+/// Depths needed in the window before outliers are detected
+const MIN_HISTORY: usize = 5;
+
+/// A line of a log which is not a plausible depth
+#[derive(Debug, Clone, PartialEq)]
+pub enum Anomaly {
+    /// Blank line, the sonar returned nothing
+    Dropout { line: usize },
+    /// Line which is not a number
+    Malformed { line: usize, text: String },
+    /// Depth far from the recent ones
+    Outlier {
+        line: usize,
+        depth: i64,
+        /// Median of the window
+        median: f64,
+        /// Modified z-score of the depth
+        score: f64,
+    },
+}
+
+impl fmt::Display for Anomaly {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Anomaly::Dropout { line } => write!(f, "line {}: dropout", line),
+            Anomaly::Malformed { line, text } => write!(f, "line {}: malformed `{}`", line, text),
+            Anomaly::Outlier {
+                line,
+                depth,
+                median,
+                score,
+            } => write!(
+                f,
+                "line {}: outlier {} (median {}, score {:.1})",
+                line, depth, median, score
+            ),
+        }
+    }
+}
+
+/// Median of a non-empty list
+fn median(values: &mut [f64]) -> f64 {
+    values.sort_unstable_by(f64::total_cmp);
+    let middle = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (values[middle - 1] + values[middle]) / 2.0
+    } else {
+        values[middle]
+    }
+}
+
+/// Finds outliers in a stream of depths with the MAD of a trailing window
+pub struct OutlierFilter {
+    window: VecDeque<i64>,
+    size: usize,
+    threshold: f64,
+}
+
+impl OutlierFilter {
+    /// Filter with a window of `size` depths, at least 1
+    pub fn new(size: usize, threshold: f64) -> OutlierFilter {
+        assert!(size > 0, "Windows must hold at least one depth");
+        OutlierFilter {
+            window: VecDeque::with_capacity(size + 1),
+            size,
+            threshold,
+        }
+    }
+
+    /// Adds the next depth, returns the median of the window and the depth's score
+    /// if it is an outlier
+    pub fn check(&mut self, depth: i64) -> Option<(f64, f64)> {
+        let outlier = if self.window.len() >= MIN_HISTORY.min(self.size) {
+            let mut depths: Vec<f64> = self.window.iter().map(|&depth| depth as f64).collect();
+            let center = median(&mut depths);
+            let mut deviations: Vec<f64> =
+                depths.iter().map(|depth| (depth - center).abs()).collect();
+            // depths are whole numbers, a MAD of 0 in a window of equal depths counts as 1
+            let mad = median(&mut deviations).max(1.0);
+            let score = 0.6745 * (depth as f64 - center).abs() / mad;
+            (score > self.threshold).then_some((center, score))
+        } else {
+            None
+        };
+
+        self.window.push_back(depth);
+        if self.window.len() > self.size {
+            self.window.pop_front();
+        }
+        outlier
+    }
+}
+
+/// Result of reading one line of a log
+#[derive(Debug, Clone, PartialEq)]
+pub enum Reading {
+    Depth(i64),
+    Anomaly(Anomaly),
+}
+
+/// Anomaly counts, and the answers of day 1 before and after cleaning
+#[derive(Debug, Clone, PartialEq)]
+pub struct SonarReport {
+    pub lines: usize,
+    pub dropouts: usize,
+    pub malformed: usize,
+    pub outliers: usize,
+    /// Comparisons of single depths and windows of 3 (parts 1 and 2) of all depths
+    pub raw: [SweepReport; 2],
+    /// Comparisons of single depths and windows of 3 (parts 1 and 2) without outliers
+    pub cleaned: [SweepReport; 2],
+}
+
+impl fmt::Display for SonarReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "lines: {}", self.lines)?;
+        writeln!(f, "dropouts: {}", self.dropouts)?;
+        writeln!(f, "malformed: {}", self.malformed)?;
+        writeln!(f, "outliers: {}", self.outliers)?;
+        for (part, (raw, cleaned)) in self.raw.iter().zip(&self.cleaned).enumerate() {
+            writeln!(
+                f,
+                "[part {}] {} increases, {} without outliers",
+                part + 1,
+                raw.increases,
+                cleaned.increases
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// Reads a log line by line, see the module documentation
+pub struct Sonar {
+    filter: OutlierFilter,
+    line: usize,
+    anomalies: [usize; 3],
+    raw: [SweepAnalytics; 2],
+    cleaned: [SweepAnalytics; 2],
+}
+
+impl Sonar {
+    /// Outliers are found in a window of `window` depths, at least 1, with a modified z-score
+    /// above `threshold`
+    pub fn new(window: usize, threshold: f64) -> Sonar {
+        Sonar {
+            filter: OutlierFilter::new(window, threshold),
+            line: 0,
+            anomalies: [0; 3],
+            raw: [SweepAnalytics::new(1), SweepAnalytics::new(3)],
+            cleaned: [SweepAnalytics::new(1), SweepAnalytics::new(3)],
+        }
+    }
+
+    /// Reads the next line, which is either a depth of the cleaned series or an anomaly
+    pub fn read(&mut self, line: &str) -> Reading {
+        self.line += 1;
+        let text = line.trim();
+        if text.is_empty() {
+            self.anomalies[0] += 1;
+            return Reading::Anomaly(Anomaly::Dropout { line: self.line });
+        }
+        let Ok(depth) = text.parse::<i64>() else {
+            self.anomalies[1] += 1;
+            return Reading::Anomaly(Anomaly::Malformed {
+                line: self.line,
+                text: text.to_string(),
+            });
+        };
+
+        for analytics in &mut self.raw {
+            analytics.push(depth);
+        }
+        if let Some((median, score)) = self.filter.check(depth) {
+            self.anomalies[2] += 1;
+            return Reading::Anomaly(Anomaly::Outlier {
+                line: self.line,
+                depth,
+                median,
+                score,
+            });
+        }
+        for analytics in &mut self.cleaned {
+            analytics.push(depth);
+        }
+        Reading::Depth(depth)
+    }
+
+    /// Report of the lines read so far
+    pub fn report(&self) -> SonarReport {
+        let [dropouts, malformed, outliers] = self.anomalies;
+        SonarReport {
+            lines: self.line,
+            dropouts,
+            malformed,
+            outliers,
+            raw: self
+                .raw
+                .each_ref()
+                .map(|analytics| analytics.report().clone()),
+            cleaned: self
+                .cleaned
+                .each_ref()
+                .map(|analytics| analytics.report().clone()),
+        }
+    }
+}
+
+/// A log read with `Sonar`
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanedLog {
+    pub depths: Vec<i64>,
+    pub anomalies: Vec<Anomaly>,
+    pub report: SonarReport,
+}
+
+/// Reads a whole log, see `Sonar`. `window` is at least 1.
+pub fn clean(log: &str, window: usize, threshold: f64) -> CleanedLog {
+    let mut sonar = Sonar::new(window, threshold);
+    let mut depths = vec![];
+    let mut anomalies = vec![];
+    for line in log.lines() {
+        match sonar.read(line) {
+            Reading::Depth(depth) => depths.push(depth),
+            Reading::Anomaly(anomaly) => anomalies.push(anomaly),
+        }
+    }
+    CleanedLog {
+        depths,
+        anomalies,
+        report: sonar.report(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn anomalies() {
+        let log = "199\n200\n208\n210\n\n200\n207\nx7\n240\n269\n99999\n260\n263\n";
+        let cleaned = clean(log, DEFAULT_WINDOW, DEFAULT_THRESHOLD);
+        assert_eq!(
+            cleaned.depths,
+            [199, 200, 208, 210, 200, 207, 240, 269, 260, 263]
+        );
+        assert_eq!(cleaned.anomalies.len(), 3);
+        assert_eq!(cleaned.anomalies[0], Anomaly::Dropout { line: 5 });
+        assert_eq!(cleaned.anomalies[1].to_string(), "line 8: malformed `x7`");
+        assert!(matches!(
+            cleaned.anomalies[2],
+            Anomaly::Outlier {
+                line: 11,
+                depth: 99999,
+                ..
+            }
+        ));
+
+        let report = cleaned.report;
+        assert_eq!(
+            (
+                report.lines,
+                report.dropouts,
+                report.malformed,
+                report.outliers
+            ),
+            (13, 1, 1, 1)
+        );
+        assert_eq!(report.cleaned[0].increases, 7);
+        assert_eq!(report.cleaned[1].increases, 5);
+        assert_eq!(report.raw[0].increases, 8);
+    }
+
+    #[test]
+    fn lasting_changes_are_accepted() {
+        let mut filter = OutlierFilter::new(9, DEFAULT_THRESHOLD);
+        let outliers: Vec<bool> = [10, 11, 10, 12, 11, 10, 500, 501, 502, 500, 501, 502]
+            .into_iter()
+            .map(|depth| filter.check(depth).is_some())
+            .collect();
+        // the new depth is accepted once it fills more than half of the window
+        assert_eq!(
+            outliers,
+            [false, false, false, false, false, false, true, true, true, true, true, false]
+        );
+
+        // a window of one depth only compares with the previous depth
+        let mut filter = OutlierFilter::new(1, DEFAULT_THRESHOLD);
+        assert_eq!(filter.check(10), None);
+        assert_eq!(filter.check(11), None);
+        assert!(filter.check(500).is_some());
+    }
+
+    #[test]
+    #[should_panic(expected = "Windows must hold at least one depth")]
+    fn empty_windows_are_rejected() {
+        clean("199\n200\n", 0, DEFAULT_THRESHOLD);
+    }
+}
@@ -0,0 +1,87 @@
+//! Animations of simulations in the terminal
+use std::io::{self, Write};
+use std::thread;
+use std::time::Duration;
+
+pub const DEFAULT_DELAY: Duration = Duration::from_millis(100);
+
+/// Moves the cursor to the top left corner and clears the screen below it
+const REDRAW: &str = "\x1b[H\x1b[J";
+
+/// One state of a simulation, drawn as text
+pub trait Frame {
+    /// Lines of the frame, without a trailing line break
+    fn render(&self) -> String;
+}
+
+/// Writes frames one after another, waiting `delay` after each one
+pub struct Player<W: Write> {
+    out: W,
+    delay: Duration,
+    /// Each frame replaces the previous one using ANSI escape codes,
+    /// otherwise frames are separated by an empty line (e.g. when writing to a file)
+    redraw: bool,
+}
+
+impl<W: Write> Player<W> {
+    pub fn new(out: W, delay: Duration, redraw: bool) -> Player<W> {
+        Player { out, delay, redraw }
+    }
+
+    /// Streams the frames as they are produced and returns how many were written
+    pub fn play<F: Frame, I: IntoIterator<Item = F>>(&mut self, frames: I) -> io::Result<usize> {
+        let mut count = 0;
+        for frame in frames {
+            if self.redraw {
+                write!(self.out, "{}", REDRAW)?;
+            } else if count > 0 {
+                writeln!(self.out)?;
+            }
+            writeln!(self.out, "{}", frame.render())?;
+            self.out.flush()?;
+            count += 1;
+            if !self.delay.is_zero() {
+                thread::sleep(self.delay);
+            }
+        }
+        Ok(count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Counter(u32);
+
+    impl Frame for Counter {
+        fn render(&self) -> String {
+            format!("frame {}", self.0)
+        }
+    }
+
+    #[test]
+    fn frames_without_redraw() {
+        let mut out = vec![];
+        let count = Player::new(&mut out, Duration::ZERO, false)
+            .play((1..=3).map(Counter))
+            .unwrap();
+        assert_eq!(count, 3);
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "frame 1\n\nframe 2\n\nframe 3\n"
+        );
+    }
+
+    #[test]
+    fn frames_with_redraw() {
+        let mut out = vec![];
+        Player::new(&mut out, Duration::ZERO, true)
+            .play((1..=2).map(Counter))
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "\x1b[H\x1b[Jframe 1\n\x1b[H\x1b[Jframe 2\n"
+        );
+    }
+}
@@ -7,20 +7,16 @@ if [ -z "$day_number" ]; then
     exit 1
 fi
 
-filename=`find ./src/bin -name "day_${day_number}_*" -printf "%f\n" | head -1 | sed 's/.rs//'`
-
-if [ -z "$filename" ]; then
-    echo "Cannot find executable for day $day_number"
+# module name from the registry, e.g. day_11_dumbo_octopus
+name=$(cargo run --quiet --bin aoc -- days "$day_number" | grep -v "^#" | cut -f2)
+if [ -z "$name" ]; then
     exit 1
 fi
 
-test_input_file="test_inputs/${filename}.txt"
-
-if [[ -f "$test_input_file" ]]; then
-    command="cargo run --bin ${filename} -- ${test_input_file}"
-else
-    command="cargo test --bin ${filename}"
-fi
+command="cargo test --lib days::${name}::"
+echo $command
+$command
 
+command="cargo run --bin aoc -- run ${day_number} --test-input"
 echo $command
 $command
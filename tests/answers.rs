@@ -0,0 +1,114 @@
+//! Runs every solver on recorded inputs and compares the answers with `answers.tsv`,
+//! and on the test inputs, comparing the answers with the example answers of the registry
+use advent_of_code_2021::answers::{self, KnownAnswer};
+use advent_of_code_2021::registry;
+use std::fs;
+use std::path::Path;
+
+/// Parts marked slow in the registry take too long to compute in a regular test run.
+/// Run them with `cargo test -- --ignored`.
+fn is_slow(recorded: &KnownAnswer) -> bool {
+    registry::find(recorded.day).is_some_and(|day| day.is_slow(recorded.part))
+}
+
+fn recorded_answers() -> Vec<KnownAnswer> {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    answers::load(manifest_dir.join(answers::ANSWERS_PATH)).unwrap()
+}
+
+fn check_answers<F: Fn(&KnownAnswer) -> bool>(filter: F) {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut mismatches = vec![];
+    for recorded in recorded_answers()
+        .iter()
+        .filter(|recorded| filter(recorded))
+    {
+        let day = registry::find(recorded.day).expect("Day is not registered");
+        let input =
+            fs::read_to_string(manifest_dir.join(&recorded.input_path)).expect("Cannot read input");
+        let result = day
+            .solver
+            .solve(&input, &[recorded.part])
+            .expect("Cannot parse input");
+        let answer = &result.parts[0].answer;
+        if *answer != recorded.answer {
+            mismatches.push(format!(
+                "day {} part {} ({}): expected {:?}, got {:?}",
+                recorded.day, recorded.part, recorded.input_path, recorded.answer, answer
+            ));
+        }
+    }
+
+    assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn recorded_answers_are_covered_by_registry() {
+    for recorded in recorded_answers() {
+        assert!(
+            registry::find(recorded.day).is_some(),
+            "Day {} is not registered",
+            recorded.day
+        );
+    }
+}
+
+#[test]
+fn every_registered_day_has_recorded_answers() {
+    let recorded = recorded_answers();
+    for day in registry::DAYS {
+        for part in [1, 2] {
+            assert!(
+                day.example_answer(part).is_some()
+                    || recorded
+                        .iter()
+                        .any(|r| r.day == day.number && r.part == part),
+                "Day {} part {} has no recorded or example answer",
+                day.number,
+                part
+            );
+        }
+    }
+}
+
+#[test]
+fn example_answers_match_registry() {
+    let manifest_dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let mut mismatches = vec![];
+    for day in registry::DAYS {
+        let test_input = fs::read_to_string(manifest_dir.join(day.test_input_path()));
+        let Some(example_answers) = day.example_answers else {
+            assert!(
+                test_input.is_err(),
+                "Day {} has no example answers",
+                day.number
+            );
+            continue;
+        };
+        let result = day
+            .solver
+            .solve(&test_input.expect("Cannot read test input"), &[1, 2])
+            .expect("Cannot parse test input");
+        for (part, expected) in result.parts.iter().zip(example_answers) {
+            if part.answer != expected {
+                mismatches.push(format!(
+                    "day {} part {} (example): expected {:?}, got {:?}",
+                    day.number, part.part, expected, part.answer
+                ));
+            }
+        }
+    }
+
+    assert!(mismatches.is_empty(), "\n{}", mismatches.join("\n"));
+}
+
+#[test]
+fn answers_match_recorded() {
+    check_answers(|recorded| !is_slow(recorded));
+}
+
+#[test]
+#[ignore]
+fn slow_answers_match_recorded() {
+    check_answers(is_slow);
+}
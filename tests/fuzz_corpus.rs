@@ -0,0 +1,82 @@
+//! Replays the corpora of the fuzz targets in `fuzz/`, without a fuzzer.
+//! Inputs named `regression_*` were accepted or crashed before, they must be clean errors now.
+use advent_of_code_2021::days::day_14_extended_polymerization::ExtendedPolymerization;
+use advent_of_code_2021::days::day_16_packet_decoder::PacketDecoder;
+use advent_of_code_2021::days::day_17_trick_shot::TrickShot;
+use advent_of_code_2021::days::day_18_snailfish::Snailfish;
+use advent_of_code_2021::days::day_23_amphipod::Amphipods;
+use advent_of_code_2021::Solution;
+use std::fmt::Debug;
+use std::fs;
+
+/// Checks every input of a target's corpus: either a clean error,
+/// or a value which `write` turns into an input parsed as the same value
+fn replay<S: Solution>(target: &str, solution: S, write: impl Fn(&S::Input) -> String)
+where
+    S::Input: PartialEq + Debug,
+{
+    let mut paths: Vec<_> = fs::read_dir(format!("fuzz/corpus/{}", target))
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .collect();
+    paths.sort();
+    assert!(!paths.is_empty(), "Empty corpus for {}", target);
+
+    for path in paths {
+        let input = fs::read_to_string(&path).unwrap();
+        let parsed = solution.parse(&input);
+        let is_regression = path
+            .file_name()
+            .unwrap()
+            .to_string_lossy()
+            .starts_with("regression_");
+        match parsed {
+            Ok(value) if is_regression => {
+                panic!("{} is accepted again: {:?}", path.display(), value)
+            }
+            Ok(value) => assert_eq!(
+                solution.parse(&write(&value)).unwrap(),
+                value,
+                "{} does not round-trip",
+                path.display()
+            ),
+            Err(_) => assert!(is_regression, "{} is rejected", path.display()),
+        }
+    }
+}
+
+#[test]
+fn day_14_extended_polymerization() {
+    replay(
+        "day_14_extended_polymerization",
+        ExtendedPolymerization::PUZZLE,
+        |polymerization| polymerization.to_string(),
+    );
+}
+
+#[test]
+fn day_16_packet_decoder() {
+    replay("day_16_packet_decoder", PacketDecoder, |packet| {
+        packet.to_string()
+    });
+}
+
+#[test]
+fn day_17_trick_shot() {
+    replay("day_17_trick_shot", TrickShot, |target_area| {
+        target_area.to_string()
+    });
+}
+
+#[test]
+fn day_18_snailfish() {
+    replay("day_18_snailfish", Snailfish, |numbers| {
+        let lines: Vec<String> = numbers.iter().map(|number| number.to_string()).collect();
+        lines.join("\n")
+    });
+}
+
+#[test]
+fn day_23_amphipod() {
+    replay("day_23_amphipod", Amphipods, |burrows| burrows.to_string());
+}
@@ -0,0 +1,150 @@
+//! Fetches inputs from a local stand-in for the Advent of Code server
+use advent_of_code_2021::input_provider::{FetchError, HttpFetch, InputProvider};
+use advent_of_code_2021::registry;
+use std::fs;
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::process;
+use std::thread::{self, JoinHandle};
+
+/// Request line and headers received by the server
+struct Request {
+    request_line: String,
+    headers: Vec<String>,
+}
+
+/// Serves one response to each of `responses.len()` connections, returns the requests
+fn serve(responses: Vec<String>) -> (String, JoinHandle<Vec<Request>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = vec![];
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut lines = vec![];
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim_end().is_empty() {
+                    break;
+                }
+                lines.push(line.trim_end().to_string());
+            }
+            stream.write_all(response.as_bytes()).unwrap();
+            requests.push(Request {
+                request_line: lines.remove(0),
+                headers: lines,
+            });
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+fn ok(body: &str) -> String {
+    format!(
+        "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        body.len(),
+        body
+    )
+}
+
+/// Empty cache directory for one test
+fn cache_dir(test_name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("aoc_inputs_{}_{}", process::id(), test_name));
+    let _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn fetches_missing_input_and_caches_it() {
+    let (base_url, server) = serve(vec![ok("199\n200\n208\n")]);
+    let cache_dir = cache_dir("fetch");
+    let provider = InputProvider::new(
+        &cache_dir,
+        Some(Box::new(HttpFetch::new(&base_url, "secret").unwrap())),
+    );
+    let day = registry::find(1).unwrap();
+
+    assert!(!provider.is_cached(day));
+    assert_eq!(provider.input(day).unwrap(), "199\n200\n208\n");
+    assert_eq!(
+        fs::read_to_string(cache_dir.join("day_1_sonar_sweep.txt")).unwrap(),
+        "199\n200\n208\n"
+    );
+
+    // the server only answers once, so this comes from the cache
+    assert_eq!(provider.input(day).unwrap(), "199\n200\n208\n");
+
+    let requests = server.join().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].request_line, "GET /2021/day/1/input HTTP/1.1");
+    assert!(requests[0]
+        .headers
+        .contains(&"Cookie: session=secret".to_string()));
+    fs::remove_dir_all(cache_dir).unwrap();
+}
+
+#[test]
+fn base_url_path_is_kept() {
+    let (base_url, server) = serve(vec![ok("on x=1..2,y=1..2,z=1..2\n")]);
+    let cache_dir = cache_dir("prefix");
+    let provider = InputProvider::new(
+        &cache_dir,
+        Some(Box::new(
+            HttpFetch::new(&format!("{}/mirror/", base_url), "secret").unwrap(),
+        )),
+    );
+
+    provider.input(registry::find(22).unwrap()).unwrap();
+
+    let requests = server.join().unwrap();
+    assert_eq!(
+        requests[0].request_line,
+        "GET /mirror/2021/day/22/input HTTP/1.1"
+    );
+    fs::remove_dir_all(cache_dir).unwrap();
+}
+
+#[test]
+fn failed_fetch_is_not_cached() {
+    let (base_url, server) = serve(vec![
+        "HTTP/1.1 400 Bad Request\r\nConnection: close\r\n\r\nPlease log in.".to_string(),
+    ]);
+    let cache_dir = cache_dir("failed");
+    let provider = InputProvider::new(
+        &cache_dir,
+        Some(Box::new(HttpFetch::new(&base_url, "expired").unwrap())),
+    );
+    let day = registry::find(2).unwrap();
+
+    match provider.input(day) {
+        Err(FetchError::Http { status, body }) => {
+            assert_eq!(status, 400);
+            assert_eq!(body, "Please log in.");
+        }
+        other => panic!("Expected an HTTP error, got {:?}", other),
+    }
+    assert!(!provider.is_cached(day));
+    server.join().unwrap();
+}
+
+#[test]
+fn cache_only_without_session() {
+    let cache_dir = cache_dir("offline");
+    fs::create_dir_all(&cache_dir).unwrap();
+    fs::write(cache_dir.join("day_3_binary_diagnostic.txt"), "00100\n").unwrap();
+    let provider = InputProvider::new(&cache_dir, None);
+
+    assert_eq!(
+        provider.input(registry::find(3).unwrap()).unwrap(),
+        "00100\n"
+    );
+    assert!(matches!(
+        provider.input(registry::find(4).unwrap()),
+        Err(FetchError::MissingSession)
+    ));
+    fs::remove_dir_all(cache_dir).unwrap();
+}